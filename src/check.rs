@@ -0,0 +1,209 @@
+use dicetest::hints::{self, Hints};
+use std::cell::Cell;
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Describes a property that doesn't hold.
+///
+/// It's returned by [`check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropFailure {
+    /// The innermost hint section that contains the failed assertion (e.g.
+    /// ``"Is `+` associative?"``). For composite properties it's the section of the property that
+    /// failed, not the section of the whole structure.
+    ///
+    /// It's `None` if the assertion was not inside of a hint section or if hints are disabled.
+    pub section: Option<String>,
+    /// The label of the failed assertion (e.g. `"(((x + y) + z) == (x + (y + z)))"`).
+    pub assertion: String,
+    /// The hints that were logged until the assertion failed.
+    ///
+    /// It's empty if hints are disabled.
+    pub hints: Hints,
+}
+
+impl Display for PropFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(section) = &self.section {
+            write!(f, "{} ", section)?;
+        }
+        write!(f, "assertion failed: {}", self.assertion)
+    }
+}
+
+impl std::error::Error for PropFailure {}
+
+/// Evaluates the given property without panicking if an assertion fails.
+///
+/// All properties in this crate use [`ops::assert`] for their assertions. Inside of this
+/// function, the first failed assertion aborts the evaluation of `prop` and its label will be
+/// returned as [`PropFailure`]. This allows to use the properties outside of tests, e.g. for
+/// validating values at runtime.
+///
+/// Panics that are not caused by [`ops::assert`] will be propagated. The failed assertion
+/// unwinds the stack, hence `prop` must not be compiled with `panic = "abort"`.
+///
+/// # Examples
+///
+/// ```
+/// use diceprop::{props, Elem, Fun2, Vars};
+///
+/// let vars = Vars::new(
+///     "f64",
+///     [Elem::new("x", 0.1), Elem::new("y", 0.2), Elem::new("z", 0.3)],
+/// );
+/// let add = Fun2::infix("+", |x, y| x + y);
+///
+/// let failure = diceprop::check(|| props::binop::associative(vars, add)).unwrap_err();
+///
+/// assert_eq!(failure.section.unwrap(), "Is `+` associative?");
+/// assert_eq!(failure.assertion, "(((x + y) + z) == (x + (y + z)))");
+/// ```
+///
+/// [`ops::assert`]: crate::ops::assert
+pub fn check(prop: impl FnOnce()) -> Result<(), PropFailure> {
+    let (result, hints) = hints::collect(|| {
+        let _mode = CheckMode::enter();
+        panic::catch_unwind(AssertUnwindSafe(prop))
+    });
+
+    match result {
+        Ok(()) => Ok(()),
+        Err(payload) => match payload.downcast::<AssertionFailed>() {
+            Ok(assertion_failed) => Err(PropFailure {
+                section: innermost_section(&hints),
+                assertion: assertion_failed.0,
+                hints,
+            }),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+/// The panic payload of a failed assertion inside of [`check`].
+struct AssertionFailed(String);
+
+thread_local! {
+    static CHECK_MODE_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CheckMode;
+
+impl CheckMode {
+    fn enter() -> Self {
+        CHECK_MODE_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        CheckMode
+    }
+}
+
+impl Drop for CheckMode {
+    fn drop(&mut self) {
+        CHECK_MODE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Whether the thread that created it was inside of [`check`].
+///
+/// The check mode is thread-local, hence properties that evaluate assertions on spawned threads
+/// must capture it beforehand and enter it on each spawned thread.
+#[derive(Clone, Copy)]
+pub(crate) struct CheckModeScope(bool);
+
+impl CheckModeScope {
+    /// Captures the check mode of the current thread.
+    pub(crate) fn current() -> Self {
+        CheckModeScope(CHECK_MODE_DEPTH.with(Cell::get) > 0)
+    }
+
+    /// Runs `f` with the captured check mode.
+    pub(crate) fn run<R>(self, f: impl FnOnce() -> R) -> R {
+        let _mode = self.0.then(CheckMode::enter);
+        f()
+    }
+}
+
+/// Aborts the current evaluation because the assertion with the given label failed.
+///
+/// Inside of [`check`] this function unwinds without invoking the panic hook, otherwise it
+/// panics with the usual message.
+pub(crate) fn fail(assertion: String) -> ! {
+    if CHECK_MODE_DEPTH.with(Cell::get) > 0 {
+        panic::resume_unwind(Box::new(AssertionFailed(assertion)))
+    } else {
+        panic!("assertion failed: {}", assertion)
+    }
+}
+
+fn innermost_section(hints: &Hints) -> Option<String> {
+    let last = hints.0.last()?;
+    hints
+        .0
+        .iter()
+        .rev()
+        .find(|hint| hint.indent < last.indent)
+        .map(|hint| hint.text.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{check, props, Elem, Fun1, Fun2, Set, Vars};
+
+    #[test]
+    fn check_returns_ok_if_prop_holds() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+
+            let result = check(|| props::algebra::ring(vars, add, mul, neg, zero, one));
+
+            assert_eq!(result, Ok(()));
+        })
+    }
+
+    #[test]
+    fn check_returns_first_failure() {
+        let vars = Vars::new(
+            "f64",
+            [
+                Elem::new("x", 0.1),
+                Elem::new("y", 0.2),
+                Elem::new("z", 0.3),
+            ],
+        );
+        let add = Fun2::infix("+", |x, y| x + y);
+        let mul = Fun2::infix("*", |x, y| x * y);
+        let neg = Fun1::new("-", |x: f64| -x);
+        let zero = Elem::new("zero", 0.0);
+        let one = Elem::new("one", 1.0);
+
+        let failure = check(|| props::algebra::ring(vars, add, mul, neg, zero, one)).unwrap_err();
+
+        assert_eq!(failure.section.as_deref(), Some("Is `+` associative?"));
+        assert_eq!(failure.assertion, "(((x + y) + z) == (x + (y + z)))");
+        assert_eq!(
+            failure.hints.0.last().unwrap().text,
+            "(((x + y) + z) == (x + (y + z))) = false"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: ((x == y) || (y == x))")]
+    fn assert_panics_outside_of_check() {
+        let vars = Vars::new("u8", [Elem::new("x", 1), Elem::new("y", 2)]);
+        let eq = Fun2::infix("==", |x, y| x == y);
+        props::binrel::connex(vars, eq);
+    }
+
+    #[test]
+    #[should_panic(expected = "not an assertion")]
+    fn check_propagates_other_panics() {
+        let _ = check(|| panic!("not an assertion"));
+    }
+}
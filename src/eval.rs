@@ -1,6 +1,24 @@
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+
+use crate::{ops, Fun1, Fun1Label, Fun2, Fun2Label};
 
 /// Represents a evaluated expression of type `S`.
+///
+/// The operators of [`std::ops`] are implemented for [`Eval`] if they are implemented for the
+/// values. The resulting [`Eval`] has a label that describes the operation (e.g. `(x + y)` or
+/// `-(x)`). Comparisons are available via methods like [`Eval::eq_`] and [`Eval::le_`].
+/// Like the other operations on [`Eval`], they will log their result via [`dicetest::hints`].
+///
+/// ```
+/// use diceprop::{ops, Elem};
+///
+/// let x = Elem::new("x", 2).eval();
+/// let y = Elem::new("y", 3).eval();
+///
+/// // Logs `(x + y) = 5` and `((x + y) == (y + x)) = true`
+/// ops::assert((x + y).eq_(&(y + x)));
+/// ```
 #[derive(Clone, Copy)]
 pub struct Eval<L: Display + Copy, S> {
     /// A human-readable label that describes the expression that was evaluated
@@ -20,6 +38,14 @@ impl<L: Display + Copy, S> Eval<L, S> {
     }
 }
 
+impl<'a, S> Eval<&'a str, S> {
+    /// Returns an [`Eval`] with the given label for a value that was not evaluated by a labelled
+    /// function, e.g. a function that is passed to a higher-order function.
+    pub(crate) fn labelled(label: &'a str, value: S) -> Self {
+        Eval { label, value }
+    }
+}
+
 impl<'a, L: Display + Copy, S> Eval<L, &'a S> {
     /// Returns an [`Eval`] with the same label and a clone of the original value.
     pub fn cloned(self) -> Eval<L, S>
@@ -43,3 +69,159 @@ impl<'a, L: Display + Copy, S> Eval<L, &'a S> {
         }
     }
 }
+
+impl<L: Display + Copy, S> Eval<L, S> {
+    /// The values are equal based on [`PartialEq`] (see [`ops::eq`]).
+    pub fn eq_<L2>(&self, rhs: &Eval<L2, S>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+        S: Debug + PartialEq,
+    {
+        ops::eq(self.as_ref(), rhs.as_ref())
+    }
+
+    /// The values are not equal based on [`PartialEq`] (see [`ops::ne`]).
+    pub fn ne_<L2>(&self, rhs: &Eval<L2, S>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+        S: Debug + PartialEq,
+    {
+        ops::ne(self.as_ref(), rhs.as_ref())
+    }
+
+    /// The first value is less than the second value based on [`PartialOrd`] (see [`ops::lt`]).
+    pub fn lt_<L2>(&self, rhs: &Eval<L2, S>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+        S: Debug + PartialOrd,
+    {
+        ops::lt(self.as_ref(), rhs.as_ref())
+    }
+
+    /// The first value is less than or equal to the second value based on [`PartialOrd`]
+    /// (see [`ops::le`]).
+    pub fn le_<L2>(&self, rhs: &Eval<L2, S>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+        S: Debug + PartialOrd,
+    {
+        ops::le(self.as_ref(), rhs.as_ref())
+    }
+
+    /// The first value is greater than the second value based on [`PartialOrd`]
+    /// (see [`ops::gt`]).
+    pub fn gt_<L2>(&self, rhs: &Eval<L2, S>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+        S: Debug + PartialOrd,
+    {
+        ops::gt(self.as_ref(), rhs.as_ref())
+    }
+
+    /// The first value is greater than or equal to the second value based on [`PartialOrd`]
+    /// (see [`ops::ge`]).
+    pub fn ge_<L2>(&self, rhs: &Eval<L2, S>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+        S: Debug + PartialOrd,
+    {
+        ops::ge(self.as_ref(), rhs.as_ref())
+    }
+}
+
+impl<L: Display + Copy> Eval<L, bool> {
+    /// Logical implication (see [`ops::implies`]).
+    pub fn implies<L2>(self, consequent: Eval<L2, bool>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+    {
+        ops::implies(self, consequent)
+    }
+
+    /// Logical equivalence (see [`ops::iff`]).
+    pub fn iff<L2>(self, rhs: Eval<L2, bool>) -> Eval<Fun2Label<'static, L, L2>, bool>
+    where
+        L2: Display + Copy,
+    {
+        ops::iff(self, rhs)
+    }
+}
+
+macro_rules! impl_unary_op {
+    ($trait:ident, $method:ident, $name:literal) => {
+        impl<L, S> $trait for Eval<L, S>
+        where
+            L: Display + Copy,
+            S: $trait,
+            S::Output: Debug,
+        {
+            type Output = Eval<Fun1Label<'static, L>, S::Output>;
+
+            fn $method(self) -> Self::Output {
+                Fun1::new($name, S::$method).eval_once(self)
+            }
+        }
+    };
+}
+
+impl_unary_op!(Neg, neg, "-");
+impl_unary_op!(Not, not, "!");
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $name:literal) => {
+        impl<L1, L2, S1, S2> $trait<Eval<L2, S2>> for Eval<L1, S1>
+        where
+            L1: Display + Copy,
+            L2: Display + Copy,
+            S1: $trait<S2>,
+            S1::Output: Debug,
+        {
+            type Output = Eval<Fun2Label<'static, L1, L2>, S1::Output>;
+
+            fn $method(self, rhs: Eval<L2, S2>) -> Self::Output {
+                Fun2::infix($name, S1::$method).eval_once(self, rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, "+");
+impl_binary_op!(Sub, sub, "-");
+impl_binary_op!(Mul, mul, "*");
+impl_binary_op!(Div, div, "/");
+impl_binary_op!(Rem, rem, "%");
+impl_binary_op!(BitAnd, bitand, "&");
+impl_binary_op!(BitOr, bitor, "|");
+impl_binary_op!(BitXor, bitxor, "^");
+impl_binary_op!(Shl, shl, "<<");
+impl_binary_op!(Shr, shr, ">>");
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{ops, Set};
+
+    #[test]
+    fn arithmetic_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let [x, y, z] = fate.roll(set.vars(["x", "y", "z"])).eval();
+            let lhs = x * (y + z);
+            let rhs = x * y + x * z;
+            ops::assert(lhs.eq_(&rhs));
+        })
+    }
+
+    #[test]
+    fn logic_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("u8", dice::u8(..));
+            let [x, y] = fate.roll(set.vars(["x", "y"])).eval();
+            let min = x.as_ref() & y.as_ref();
+            ops::assert(min.le_(&x) & min.le_(&y));
+            ops::assert(x.lt_(&y).implies(!y.le_(&x)));
+            ops::assert(x.ge_(&y).iff(!x.lt_(&y)) | x.ne_(&x));
+        })
+    }
+}
@@ -47,7 +47,7 @@ impl<'a, F> Fun1<'a, F> {
         Self { name, syntax, f }
     }
 
-    fn label<AL1>(&self, arg_1_label: AL1) -> Fun1Label<'a, AL1>
+    pub(crate) fn label<AL1>(&self, arg_1_label: AL1) -> Fun1Label<'a, AL1>
     where
         AL1: Display + Copy,
     {
@@ -58,6 +58,11 @@ impl<'a, F> Fun1<'a, F> {
         }
     }
 
+    /// Returns the function itself without a label.
+    pub(crate) fn f(&self) -> &F {
+        &self.f
+    }
+
     /// Returns an [`Eval`] that contains the result of the function applied to the given
     /// argument.
     ///
@@ -164,7 +169,11 @@ impl<'a, F> Fun2<'a, F> {
         Self { name, syntax, f }
     }
 
-    fn label<AL1, AL2>(&self, arg_1_label: AL1, arg_2_label: AL2) -> Fun2Label<'a, AL1, AL2>
+    pub(crate) fn label<AL1, AL2>(
+        &self,
+        arg_1_label: AL1,
+        arg_2_label: AL2,
+    ) -> Fun2Label<'a, AL1, AL2>
     where
         AL1: Display + Copy,
         AL2: Display + Copy,
@@ -177,6 +186,11 @@ impl<'a, F> Fun2<'a, F> {
         }
     }
 
+    /// Returns the function itself without a label.
+    pub(crate) fn f(&self) -> &F {
+        &self.f
+    }
+
     /// Returns an [`Eval`] that contains the result of the function applied to the given
     /// arguments.
     ///
@@ -274,7 +288,7 @@ impl<'a, F> Fun3<'a, F> {
         Self { name, f }
     }
 
-    fn label<AL1, AL2, AL3>(
+    pub(crate) fn label<AL1, AL2, AL3>(
         &self,
         arg_1_label: AL1,
         arg_2_label: AL2,
@@ -0,0 +1,408 @@
+use dicetest::hints::{self, Hints};
+use dicetest::{hint, hint_section};
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Display};
+
+use crate::{check, ops, Fun2, PropFailure, Vars};
+
+/// A law that is part of the definition of a mathematical structure.
+///
+/// The law is already bound to variables and functions, hence it can be checked without further
+/// arguments. Lists of laws are returned by functions like [`props::algebra::ring_laws`] and
+/// can be used to enumerate, select or check the laws of a structure.
+///
+/// [`props::algebra::ring_laws`]: crate::props::algebra::ring_laws
+pub struct Law<'a> {
+    /// The identifier of the law. It's built from the parameter names of the function that
+    /// returns the law (e.g. `"add_is_associative"`), hence it doesn't depend on the names of the
+    /// functions and elements and is a valid Rust identifier.
+    pub id: String,
+    /// The human-readable name of the law. It's equal to the hint section that is logged when
+    /// the law is checked (e.g. ``"Is `+` associative?"``).
+    pub name: String,
+    /// The formal statement of the law using the names of the variables and functions
+    /// (e.g. `"(((x + y) + z) == (x + (y + z)))"`). If the law consists of several assertions,
+    /// they are separated by `", "`.
+    pub statement: String,
+    /// The number of variables the law depends on.
+    pub arity: usize,
+    check: Box<dyn Fn() + 'a>,
+}
+
+impl<'a> Law<'a> {
+    /// Creates a [`Law`]. The function `check` must assert that the law holds (e.g. by calling
+    /// one of the properties in [`props`]).
+    ///
+    /// [`props`]: crate::props
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        statement: impl Into<String>,
+        arity: usize,
+        check: impl Fn() + 'a,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            statement: statement.into(),
+            arity,
+            check: Box::new(check),
+        }
+    }
+
+    /// Asserts that the law holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the law doesn't hold.
+    pub fn assert(&self) {
+        (self.check)()
+    }
+
+    /// Checks whether the law holds without panicking (see [`check`]).
+    pub fn check(&self) -> Result<(), PropFailure> {
+        check(|| (self.check)())
+    }
+}
+
+impl<'a> Debug for Law<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Law")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("statement", &self.statement)
+            .field("arity", &self.arity)
+            .finish()
+    }
+}
+
+/// Checks all laws without stopping at the first failure.
+///
+/// Each law is checked with [`Law::check`]. Returns all failures in the order of the laws.
+pub fn check_laws(laws: &[Law]) -> Result<(), Vec<PropFailure>> {
+    let failures = laws
+        .iter()
+        .filter_map(|law| law.check().err())
+        .collect::<Vec<_>>();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
+/// Asserts that all laws hold. In contrast to asserting the laws one by one, all laws will be
+/// checked even if one of them fails.
+///
+/// Each failure will be logged in its own section via [`dicetest::hints`]. The section contains
+/// the hints that were logged while checking the law, followed by the failed assertion.
+///
+/// # Panics
+///
+/// Panics if at least one of the laws doesn't hold.
+///
+/// # Examples
+///
+/// ```
+/// use diceprop::{props, Elem, Fun1, Fun2, Set};
+/// use dicetest::prelude::*;
+///
+/// Dicetest::once().run(|mut fate| {
+///     let set = Set::new("i64", dice::i64(-1000..=1000));
+///     let vars = fate.roll(set.vars(["x", "y", "z"]));
+///     let add = Fun2::infix("+", |x, y| x + y);
+///     let mul = Fun2::infix("*", |x, y| x * y);
+///     let neg = Fun1::new("-", |x: i64| -x);
+///     let zero = Elem::new("zero", 0);
+///     let one = Elem::new("one", 1);
+///
+///     diceprop::assert_laws(&props::algebra::ring_laws(vars, add, mul, neg, zero, one));
+/// })
+/// ```
+pub fn assert_laws(laws: &[Law]) {
+    let failures = laws
+        .iter()
+        .filter_map(|law| law.check().err().map(|failure| (law, failure)))
+        .collect::<Vec<_>>();
+
+    if !failures.is_empty() {
+        let count = failures.len();
+
+        for (i, (law, failure)) in failures.iter().enumerate() {
+            hint_section!("Failure {} of {}: {}", i + 1, count, law.name);
+            replay_hints(&failure.hints);
+            hint!("assertion failed: {}", failure.assertion);
+        }
+
+        let assertions = failures
+            .iter()
+            .map(|(_, failure)| failure.assertion.as_str())
+            .collect::<Vec<_>>();
+        check::fail(assertions.join(", "));
+    }
+}
+
+/// Returns the law with the given id. Used by [`laws!`] to select the law of a generated test.
+///
+/// Only the selected law is built, the builders of the other laws just record their ids.
+///
+/// # Panics
+///
+/// Panics if the ids of the laws differ from `ids`, i.e. if the generated tests don't cover
+/// the laws exactly.
+///
+/// [`laws!`]: crate::laws
+#[doc(hidden)]
+pub fn select_law<'a>(id: &str, ids: &[&str], laws: impl FnOnce() -> Vec<Law<'a>>) -> Law<'a> {
+    let (laws, seen_ids) = {
+        let _selection = Selection::enter(id);
+        let laws = laws();
+        let seen_ids = SELECTION.with(|selection| selection.borrow_mut().take().unwrap().seen_ids);
+        (laws, seen_ids)
+    };
+    assert_eq!(seen_ids, ids, "the generated tests don't match the laws");
+
+    laws.into_iter().next().unwrap()
+}
+
+thread_local! {
+    static SELECTION: RefCell<Option<Selection>> = const { RefCell::new(None) };
+}
+
+struct Selection {
+    id: String,
+    seen_ids: Vec<String>,
+}
+
+impl Selection {
+    fn enter(id: &str) -> SelectionGuard {
+        let selection = Selection {
+            id: id.to_string(),
+            seen_ids: Vec::new(),
+        };
+        SELECTION.with(|current| *current.borrow_mut() = Some(selection));
+        SelectionGuard
+    }
+}
+
+struct SelectionGuard;
+
+impl Drop for SelectionGuard {
+    fn drop(&mut self) {
+        SELECTION.with(|current| *current.borrow_mut() = None);
+    }
+}
+
+/// Builds the law with the given id unless [`select_law`] selected another law.
+pub(crate) fn build<'a>(id: String, build: impl FnOnce(String) -> Law<'a>) -> Option<Law<'a>> {
+    let selected = SELECTION.with(|current| match current.borrow_mut().as_mut() {
+        Some(selection) => {
+            let selected = selection.id == id;
+            selection.seen_ids.push(id.clone());
+            selected
+        }
+        None => true,
+    });
+
+    if selected {
+        Some(build(id))
+    } else {
+        None
+    }
+}
+
+/// Logs the given hints again, preserving their indent relative to the current section.
+fn replay_hints(replayed: &Hints) {
+    for hint in &replayed.0 {
+        (0..hint.indent).for_each(|_| hints::indent());
+        hints::add(|| hint.text.clone());
+        (0..hint.indent).for_each(|_| hints::unindent());
+    }
+}
+
+/// Returns the names of the variables.
+pub(crate) fn var_names<'a, S: Debug, const N: usize>(vars: &Vars<'a, S, N>) -> [&'a str; N] {
+    array_init::array_init(|i| vars.elems[i].name)
+}
+
+/// Returns the label of [`ops::eq`] for the statement of a law.
+pub(crate) fn eq(lhs: impl Display, rhs: impl Display) -> String {
+    ops::eq_fun::<()>().label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::ne`] for the statement of a law.
+pub(crate) fn ne(lhs: impl Display, rhs: impl Display) -> String {
+    ops::ne_fun::<()>().label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::lt`] for the statement of a law.
+pub(crate) fn lt(lhs: impl Display, rhs: impl Display) -> String {
+    ops::lt_fun::<()>().label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::le`] for the statement of a law.
+pub(crate) fn le(lhs: impl Display, rhs: impl Display) -> String {
+    ops::le_fun::<()>().label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::approx_eq`] for the statement of a law.
+pub(crate) fn approx_eq(lhs: impl Display, rhs: impl Display) -> String {
+    ops::approx_eq_fun(0.0f64).label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::approx_le`] for the statement of a law.
+pub(crate) fn approx_le(lhs: impl Display, rhs: impl Display) -> String {
+    ops::approx_le_fun(0.0f64).label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of the operator `op` of [`Eval`] (e.g. `*`) for the statement of a law.
+///
+/// [`Eval`]: crate::Eval
+pub(crate) fn infix(op: &str, lhs: impl Display, rhs: impl Display) -> String {
+    Fun2::infix(op, ()).label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::not`] for the statement of a law.
+pub(crate) fn not(b: impl Display) -> String {
+    ops::not_fun().label(&b).to_string()
+}
+
+/// Returns the label of [`ops::and`] for the statement of a law.
+pub(crate) fn and(lhs: impl Display, rhs: impl Display) -> String {
+    ops::and_fun().label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::or`] for the statement of a law.
+pub(crate) fn or(lhs: impl Display, rhs: impl Display) -> String {
+    ops::or_fun().label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::implies`] for the statement of a law.
+pub(crate) fn implies(lhs: impl Display, rhs: impl Display) -> String {
+    ops::implies_fun().label(&lhs, &rhs).to_string()
+}
+
+/// Returns the label of [`ops::iff`] for the statement of a law.
+pub(crate) fn iff(lhs: impl Display, rhs: impl Display) -> String {
+    ops::iff_fun().label(&lhs, &rhs).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::hints;
+    use std::panic::{self, AssertUnwindSafe};
+
+    use crate::{props, Elem, Fun1, Fun2, Vars};
+
+    fn ring_laws_of_f64<'a>(vars: Vars<'a, f64, 3>) -> Vec<crate::Law<'a>> {
+        let add = Fun2::infix("+", |x, y| x + y);
+        let mul = Fun2::infix("*", |x, y| x * y);
+        let neg = Fun1::new("-", |x: f64| -x);
+        let zero = Elem::new("zero", 0.0);
+        let one = Elem::new("one", 1.0);
+        props::algebra::ring_laws(vars, add, mul, neg, zero, one)
+    }
+
+    #[test]
+    fn check_laws_returns_all_failures() {
+        let vars = Vars::new(
+            "f64",
+            [
+                Elem::new("x", 0.1),
+                Elem::new("y", 0.2),
+                Elem::new("z", 0.3),
+            ],
+        );
+
+        let failures = crate::check_laws(&ring_laws_of_f64(vars)).unwrap_err();
+
+        let sections = failures
+            .iter()
+            .map(|failure| failure.section.as_deref().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            [
+                "Is `+` associative?",
+                "Is `*` associative?",
+                "Is `*` right distributive over `+`?",
+            ]
+        );
+    }
+
+    #[test]
+    fn check_laws_returns_ok_if_all_laws_hold() {
+        let vars = Vars::new(
+            "f64",
+            [
+                Elem::new("x", 1.0),
+                Elem::new("y", 2.0),
+                Elem::new("z", 4.0),
+            ],
+        );
+
+        assert_eq!(crate::check_laws(&ring_laws_of_f64(vars)), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: (((x + y) + z) == (x + (y + z))), (((x * y) * z) == (x * (y * z)))"
+    )]
+    fn assert_laws_panics_with_all_failures() {
+        let vars = Vars::new(
+            "f64",
+            [
+                Elem::new("x", 0.1),
+                Elem::new("y", 0.2),
+                Elem::new("z", 0.3),
+            ],
+        );
+
+        crate::assert_laws(&ring_laws_of_f64(vars));
+    }
+
+    #[test]
+    fn assert_laws_logs_hints_of_failures() {
+        let vars = Vars::new(
+            "f64",
+            [
+                Elem::new("x", 0.1),
+                Elem::new("y", 0.2),
+                Elem::new("z", 0.3),
+            ],
+        );
+        let laws = ring_laws_of_f64(vars);
+
+        let (result, hints) =
+            hints::collect(|| panic::catch_unwind(AssertUnwindSafe(|| crate::assert_laws(&laws))));
+        assert!(result.is_err());
+
+        let start = hints
+            .0
+            .iter()
+            .position(|hint| hint.text == "Failure 1 of 3: Is `+` associative?")
+            .unwrap();
+        let section = hints.0[start + 1..]
+            .iter()
+            .take_while(|hint| hint.indent > hints.0[start].indent)
+            .map(|hint| (hint.indent - hints.0[start].indent, hint.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            section,
+            [
+                (1, "Is `+` associative?"),
+                (2, "x, y, z of f64"),
+                (2, "x = 0.1"),
+                (2, "y = 0.2"),
+                (2, "z = 0.3"),
+                (2, "(x + y) = 0.30000000000000004"),
+                (2, "((x + y) + z) = 0.6000000000000001"),
+                (2, "(y + z) = 0.5"),
+                (2, "(x + (y + z)) = 0.6"),
+                (2, "(((x + y) + z) == (x + (y + z))) = false"),
+                (1, "assertion failed: (((x + y) + z) == (x + (y + z)))"),
+            ]
+        );
+    }
+}
@@ -7,6 +7,13 @@
 //! The author does not consider this crate as stable yet. Changes will be documented in the
 //! [changelog](https://github.com/jakoschiko/diceprop/blob/master/CHANGELOG.md).
 //!
+//! # Feature flags
+//!
+//! - `num-traits` (disabled by default): Enables the module `props::num` with properties for
+//!   the traits of [num-traits](https://github.com/rust-num/num-traits).
+//! - `serde` (disabled by default): Enables the functions `props::codec::serializer` and
+//!   `props::codec::deserializer` that build codecs from [serde](https://serde.rs).
+//!
 //! # Examples
 //!
 //! ## Associative binary operation
@@ -136,6 +143,8 @@
 //! - error: assertion failed: (x ≤ x)
 //! ```
 
+mod macros;
+
 #[macro_use]
 mod eval;
 pub use eval::Eval;
@@ -152,6 +161,18 @@ pub use set::Set;
 mod fun;
 pub use fun::{Fun1, Fun1Label, Fun2, Fun2Label, Fun3, Fun3Label};
 
+mod check;
+pub use check::{check, PropFailure};
+
+mod law;
+pub use law::{assert_laws, check_laws, Law};
+
 pub mod ops;
 
 pub mod props;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::law::select_law;
+    pub use dicetest;
+}
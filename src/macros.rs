@@ -0,0 +1,602 @@
+/// Asserts that a property written as expression holds.
+///
+/// The expression is translated into the corresponding calls of [`Fun1::eval`], [`Fun2::eval`],
+/// [`Fun3::eval`] and the operations in [`ops`]. Hence it has the same labels and logs the same
+/// hints as the hand-written properties in [`props`]. For example
+///
+/// ```ignore
+/// prop!(op(op(a, b), c) == op(a, op(b, c)));
+/// ```
+///
+/// is translated into
+///
+/// ```ignore
+/// ops::assert(ops::eq(
+///     op.eval(op.eval(a.clone(), b.clone()), c.clone()).as_ref(),
+///     op.eval(a.clone(), op.eval(b.clone(), c.clone())).as_ref(),
+/// ));
+/// ```
+///
+/// The expression supports the following syntax:
+/// - `a` for an [`Eval`] (it will be cloned)
+/// - `f(a)`, `f(a, b)` and `f(a, b, c)` for a [`Fun1`], [`Fun2`] or [`Fun3`]
+/// - `a == b`, `a != b`, `a < b`, `a <= b`, `a > b` and `a >= b` for [`ops::eq`], [`ops::ne`],
+///   [`ops::lt`], [`ops::le`], [`ops::gt`] and [`ops::ge`]
+/// - `!p`, `p && q`, `p || q`, `p --> q` and `p <-> q` for [`ops::not`], [`ops::and`],
+///   [`ops::or`], [`ops::implies`] and [`ops::iff`]
+/// - `(p)` for grouping
+///
+/// The binary operators have the same precedence as in Rust (`-->` and `<->` have the lowest
+/// precedence) and are right associative.
+///
+/// # Panics
+///
+/// Panics if the property doesn't hold.
+///
+/// # Examples
+///
+/// ```
+/// use diceprop::{prop, Fun2, Set};
+/// use dicetest::prelude::*;
+/// use dicetest::hint_section;
+///
+/// Dicetest::once().run(|mut fate| {
+///     let set = Set::new("u8", dice::u8(..));
+///     let [a, b] = fate.roll(set.vars(["x", "y"])).eval();
+///     let le = Fun2::infix("<=", |x, y| x <= y);
+///     let max = Fun2::new("max", u8::max);
+///
+///     hint_section!("Is `{}` an upper bound?", max.name);
+///     prop!(le(a, max(a, b)) && le(b, max(a, b)));
+/// })
+/// ```
+///
+/// [`Fun1::eval`]: crate::Fun1::eval
+/// [`Fun2::eval`]: crate::Fun2::eval
+/// [`Fun3::eval`]: crate::Fun3::eval
+/// [`Fun1`]: crate::Fun1
+/// [`Fun2`]: crate::Fun2
+/// [`Fun3`]: crate::Fun3
+/// [`Eval`]: crate::Eval
+/// [`ops`]: crate::ops
+/// [`ops::eq`]: crate::ops::eq
+/// [`ops::ne`]: crate::ops::ne
+/// [`ops::lt`]: crate::ops::lt
+/// [`ops::le`]: crate::ops::le
+/// [`ops::gt`]: crate::ops::gt
+/// [`ops::ge`]: crate::ops::ge
+/// [`ops::not`]: crate::ops::not
+/// [`ops::and`]: crate::ops::and
+/// [`ops::or`]: crate::ops::or
+/// [`ops::implies`]: crate::ops::implies
+/// [`ops::iff`]: crate::ops::iff
+/// [`props`]: crate::props
+#[macro_export]
+macro_rules! prop {
+    ($($expr:tt)+) => {
+        $crate::ops::assert($crate::__prop!(@bool $($expr)+))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __prop {
+    // Implication and equivalence
+    (@bool $($t:tt)+) => {
+        $crate::__prop!(@split_imp [] $($t)+)
+    };
+    (@split_imp [$($l:tt)+] --> $($r:tt)+) => {
+        $crate::ops::implies($crate::__prop!(@or $($l)+), $crate::__prop!(@bool $($r)+))
+    };
+    (@split_imp [$($l:tt)+] <-> $($r:tt)+) => {
+        $crate::ops::iff($crate::__prop!(@or $($l)+), $crate::__prop!(@bool $($r)+))
+    };
+    (@split_imp [$($l:tt)*] $t:tt $($r:tt)*) => {
+        $crate::__prop!(@split_imp [$($l)* $t] $($r)*)
+    };
+    (@split_imp [$($l:tt)+]) => {
+        $crate::__prop!(@or $($l)+)
+    };
+
+    // Disjunction
+    (@or $($t:tt)+) => {
+        $crate::__prop!(@split_or [] $($t)+)
+    };
+    (@split_or [$($l:tt)+] || $($r:tt)+) => {
+        $crate::ops::or($crate::__prop!(@and $($l)+), $crate::__prop!(@or $($r)+))
+    };
+    (@split_or [$($l:tt)*] $t:tt $($r:tt)*) => {
+        $crate::__prop!(@split_or [$($l)* $t] $($r)*)
+    };
+    (@split_or [$($l:tt)+]) => {
+        $crate::__prop!(@and $($l)+)
+    };
+
+    // Conjunction
+    (@and $($t:tt)+) => {
+        $crate::__prop!(@split_and [] $($t)+)
+    };
+    (@split_and [$($l:tt)+] && $($r:tt)+) => {
+        $crate::ops::and($crate::__prop!(@cmp $($l)+), $crate::__prop!(@and $($r)+))
+    };
+    (@split_and [$($l:tt)*] $t:tt $($r:tt)*) => {
+        $crate::__prop!(@split_and [$($l)* $t] $($r)*)
+    };
+    (@split_and [$($l:tt)+]) => {
+        $crate::__prop!(@cmp $($l)+)
+    };
+
+    // Comparison
+    (@cmp $($t:tt)+) => {
+        $crate::__prop!(@split_cmp [] $($t)+)
+    };
+    (@split_cmp [$($l:tt)+] == $($r:tt)+) => {
+        $crate::__prop!(@cmp_op eq [$($l)+] [$($r)+])
+    };
+    (@split_cmp [$($l:tt)+] != $($r:tt)+) => {
+        $crate::__prop!(@cmp_op ne [$($l)+] [$($r)+])
+    };
+    (@split_cmp [$($l:tt)+] <= $($r:tt)+) => {
+        $crate::__prop!(@cmp_op le [$($l)+] [$($r)+])
+    };
+    (@split_cmp [$($l:tt)+] >= $($r:tt)+) => {
+        $crate::__prop!(@cmp_op ge [$($l)+] [$($r)+])
+    };
+    (@split_cmp [$($l:tt)+] < $($r:tt)+) => {
+        $crate::__prop!(@cmp_op lt [$($l)+] [$($r)+])
+    };
+    (@split_cmp [$($l:tt)+] > $($r:tt)+) => {
+        $crate::__prop!(@cmp_op gt [$($l)+] [$($r)+])
+    };
+    (@split_cmp [$($l:tt)*] $t:tt $($r:tt)*) => {
+        $crate::__prop!(@split_cmp [$($l)* $t] $($r)*)
+    };
+    (@split_cmp [$($l:tt)+]) => {
+        $crate::__prop!(@term $($l)+)
+    };
+    (@cmp_op $op:ident [$($l:tt)+] [$($r:tt)+]) => {
+        $crate::ops::$op(
+            $crate::__prop!(@term $($l)+).as_ref(),
+            $crate::__prop!(@term $($r)+).as_ref(),
+        )
+    };
+
+    // Terms
+    (@term ! $($t:tt)+) => {
+        $crate::ops::not($crate::__prop!(@term $($t)+))
+    };
+    (@term ($($t:tt)+)) => {
+        $crate::__prop!(@bool $($t)+)
+    };
+    (@term $f:ident ($($args:tt)*)) => {
+        $crate::__prop!(@args $f [] [] $($args)*)
+    };
+    (@term $a:ident) => {
+        $a.clone()
+    };
+
+    // Function arguments
+    (@args $f:ident [$($done:tt)*] [$($cur:tt)+] , $($rest:tt)*) => {
+        $crate::__prop!(@args $f [$($done)* [$($cur)+]] [] $($rest)*)
+    };
+    (@args $f:ident [$($done:tt)*] [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__prop!(@args $f [$($done)*] [$($cur)* $t] $($rest)*)
+    };
+    (@args $f:ident [$($done:tt)*] [$($cur:tt)+]) => {
+        $crate::__prop!(@args $f [$($done)* [$($cur)+]] [])
+    };
+    (@args $f:ident [$([$($arg:tt)+])*] []) => {
+        $f.eval($($crate::__prop!(@bool $($arg)+)),*)
+    };
+}
+
+/// Generates a `#[test]` function for each law of a structure.
+///
+/// Each declaration consists of a module name, the type and the set of the elements and the
+/// structure with its arguments. The arguments are the same as for the corresponding function
+/// that returns the laws (e.g. [`props::algebra::commutative_ring_laws`]) without the variables.
+/// The macro generates a module with one `#[test]` function per law. The test function chooses
+/// the variables `x`, `y` and `z` from the set with [`dicetest::Dicetest::repeatedly`] and
+/// asserts the law.
+///
+/// The names of the test functions are the ids of the laws (see [`Law::id`]), because the names
+/// of the operations (e.g. `+`) are not necessarily valid identifiers. Each test function builds
+/// only the law with its id and fails if the ids of the laws don't match the generated test
+/// functions.
+///
+/// The following structures are supported:
+/// - `semigroup(op)`
+/// - `monoid(op, e)`
+/// - `group(op, inv, e)`
+/// - `abelian_group(op, inv, e)`
+/// - `ring(add, mul, neg, zero, one)`
+/// - `commutative_ring(add, mul, neg, zero, one)`
+/// - `field(non_zero_set, add, mul, neg, inv, zero, one)`, the variables `x` and `y` for the
+///   inverse element are chosen from `non_zero_set`
+/// - `partial_equivalence(rel)`
+/// - `equivalence(rel)`
+/// - `partial_order(rel)`
+/// - `total_order(rel)`
+///
+/// The items of the surrounding module are imported into the generated module.
+///
+/// # Examples
+///
+/// ```
+/// use diceprop::{laws, Elem, Fun1, Fun2, Set};
+/// use dicetest::prelude::*;
+///
+/// laws! {
+///     mod i64_ring {
+///         type = i64;
+///         set = Set::new("i64", dice::i64(-1000..=1000));
+///         commutative_ring(
+///             Fun2::infix("+", |x, y| x + y),
+///             Fun2::infix("*", |x, y| x * y),
+///             Fun1::new("-", |x: i64| -x),
+///             Elem::new("zero", 0),
+///             Elem::new("one", 1),
+///         );
+///     }
+///
+///     mod u8_order {
+///         type = u8;
+///         set = Set::new("u8", dice::u8(..));
+///         total_order(Fun2::infix("<=", |x, y| x <= y));
+///     }
+/// }
+/// ```
+///
+/// This will generate tests like `i64_ring::mul_is_distributive_over_add` and
+/// `u8_order::rel_is_connex`.
+///
+/// [`props::algebra::commutative_ring_laws`]: crate::props::algebra::commutative_ring_laws
+/// [`Law::id`]: crate::Law::id
+#[macro_export]
+macro_rules! laws {
+    ($(
+        mod $name:ident {
+            type = $ty:ty;
+            set = $set:expr;
+            $structure:ident($($arg:expr),* $(,)?);
+        }
+    )*) => {
+        $(
+            #[allow(unused_imports)]
+            mod $name {
+                use super::*;
+
+                $crate::__laws!(@structure $structure, $ty, $set, [$($arg),*]);
+            }
+        )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __laws {
+    (@structure semigroup, $ty:ty, $set:expr, [$op:expr]) => {
+        $crate::__laws!(@tests algebra::semigroup_laws, $ty, $set, [], [$op], [
+            op_is_associative,
+        ]);
+    };
+    (@structure monoid, $ty:ty, $set:expr, [$op:expr, $e:expr]) => {
+        $crate::__laws!(@tests algebra::monoid_laws, $ty, $set, [], [$op, $e], [
+            op_is_associative,
+            e_is_identity_elem_of_op,
+        ]);
+    };
+    (@structure group, $ty:ty, $set:expr, [$op:expr, $inv:expr, $e:expr]) => {
+        $crate::__laws!(@tests algebra::group_laws, $ty, $set, [], [$op, $inv, $e], [
+            op_is_associative,
+            e_is_identity_elem_of_op,
+            inv_returns_inverse_elem_of_op,
+        ]);
+    };
+    (@structure abelian_group, $ty:ty, $set:expr, [$op:expr, $inv:expr, $e:expr]) => {
+        $crate::__laws!(@tests algebra::abelian_group_laws, $ty, $set, [], [$op, $inv, $e], [
+            op_is_associative,
+            e_is_identity_elem_of_op,
+            inv_returns_inverse_elem_of_op,
+            op_is_commutative,
+        ]);
+    };
+    (@structure ring, $ty:ty, $set:expr, [$add:expr, $mul:expr, $neg:expr, $zero:expr, $one:expr]) => {
+        $crate::__laws!(@ring algebra::ring_laws, $ty, $set, [], [$add, $mul, $neg, $zero, $one], []);
+    };
+    (@structure commutative_ring, $ty:ty, $set:expr, [$add:expr, $mul:expr, $neg:expr, $zero:expr, $one:expr]) => {
+        $crate::__laws!(@ring algebra::commutative_ring_laws, $ty, $set, [], [$add, $mul, $neg, $zero, $one], [
+            mul_is_commutative,
+        ]);
+    };
+    (@structure field, $ty:ty, $set:expr, [$non_zero_set:expr, $add:expr, $mul:expr, $neg:expr, $inv:expr, $zero:expr, $one:expr]) => {
+        $crate::__laws!(@ring algebra::field_laws, $ty, $set, [$non_zero_set], [$add, $mul, $neg, $inv, $zero, $one], [
+            mul_is_commutative,
+            inv_returns_inverse_elem_of_mul,
+        ]);
+    };
+    (@structure partial_equivalence, $ty:ty, $set:expr, [$rel:expr]) => {
+        $crate::__laws!(@tests binrel::partial_equivalence_laws, $ty, $set, [], [$rel], [
+            rel_is_symmetric,
+            rel_is_transitive,
+        ]);
+    };
+    (@structure equivalence, $ty:ty, $set:expr, [$rel:expr]) => {
+        $crate::__laws!(@tests binrel::equivalence_laws, $ty, $set, [], [$rel], [
+            rel_is_reflexive,
+            rel_is_symmetric,
+            rel_is_transitive,
+        ]);
+    };
+    (@structure partial_order, $ty:ty, $set:expr, [$rel:expr]) => {
+        $crate::__laws!(@tests binrel::partial_order_laws, $ty, $set, [], [$rel], [
+            rel_is_reflexive,
+            rel_is_antisymmetric,
+            rel_is_transitive,
+        ]);
+    };
+    (@structure total_order, $ty:ty, $set:expr, [$rel:expr]) => {
+        $crate::__laws!(@tests binrel::total_order_laws, $ty, $set, [], [$rel], [
+            rel_is_connex,
+            rel_is_antisymmetric,
+            rel_is_transitive,
+        ]);
+    };
+    (@ring $module:ident::$laws:ident, $ty:ty, $set:expr, $extra_sets:tt, $args:tt, [$($tests:ident,)*]) => {
+        $crate::__laws!(@tests $module::$laws, $ty, $set, $extra_sets, $args, [
+            add_is_associative,
+            zero_is_identity_elem_of_add,
+            neg_returns_inverse_elem_of_add,
+            add_is_commutative,
+            mul_is_associative,
+            one_is_identity_elem_of_mul,
+            mul_is_distributive_over_add,
+            $($tests,)*
+        ]);
+    };
+    (@tests $module:ident::$laws:ident, $ty:ty, $set:expr, $extra_sets:tt, $args:tt, $tests:tt) => {
+        $crate::__laws!(@each $module::$laws, $ty, $set, $extra_sets, $args, $tests, $tests);
+    };
+    (@each $module:ident::$laws:ident, $ty:ty, $set:expr, $extra_sets:tt, $args:tt, $all:tt, [$($test:ident,)*]) => {
+        $(
+            #[test]
+            fn $test() {
+                $crate::__laws!(@test $test, $module::$laws, $ty, $set, $extra_sets, $args, $all);
+            }
+        )*
+    };
+    (
+        @test $test:ident,
+        $module:ident::$laws:ident,
+        $ty:ty,
+        $set:expr,
+        [$($extra_set:expr),*],
+        [$($arg:expr),*],
+        [$($all:ident,)*]
+    ) => {
+        $crate::__private::dicetest::Dicetest::repeatedly().run(|mut fate| {
+            let set = $set;
+            let vars: $crate::Vars<$ty, 3> = fate.roll(set.vars(["x", "y", "z"]));
+            let law = $crate::__private::select_law(stringify!($test), &[$(stringify!($all)),*], || {
+                $crate::props::$module::$laws(vars, $(fate.roll($extra_set.vars(["x", "y"])),)* $($arg),*)
+            });
+            law.assert();
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+    use dicetest::{hint_section, hints};
+
+    use crate::{props, Elem, Fun1, Fun2, Set, Vars};
+
+    laws! {
+        mod i64_commutative_ring {
+            type = i64;
+            set = Set::new("i64", dice::i64(-1000..=1000));
+            commutative_ring(
+                Fun2::infix("+", |x, y| x + y),
+                Fun2::infix("*", |x, y| x * y),
+                Fun1::new("-", |x: i64| -x),
+                Elem::new("zero", 0),
+                Elem::new("one", 1),
+            );
+        }
+
+        mod string_equivalence {
+            type = String;
+            set = Set::new("String", dice::string(dice::char(), ..));
+            equivalence(Fun2::infix("==", |x, y| x == y));
+        }
+
+        mod u8_semigroup {
+            type = u8;
+            set = Set::new("u8", dice::u8(..));
+            semigroup(Fun2::new("max", u8::max));
+        }
+
+        mod string_monoid {
+            type = String;
+            set = Set::new("String", dice::string(dice::char(), ..));
+            monoid(
+                Fun2::infix("+", |x: String, y: String| x + &y),
+                Elem::new("empty", String::new()),
+            );
+        }
+
+        mod i64_group {
+            type = i64;
+            set = Set::new("i64", dice::i64(..));
+            group(
+                Fun2::new("wrapping_add", i64::wrapping_add),
+                Fun1::new("wrapping_neg", i64::wrapping_neg),
+                Elem::new("zero", 0),
+            );
+        }
+
+        mod i64_abelian_group {
+            type = i64;
+            set = Set::new("i64", dice::i64(..));
+            abelian_group(
+                Fun2::new("wrapping_add", i64::wrapping_add),
+                Fun1::new("wrapping_neg", i64::wrapping_neg),
+                Elem::new("zero", 0),
+            );
+        }
+
+        mod i64_ring {
+            type = i64;
+            set = Set::new("i64", dice::i64(..));
+            ring(
+                Fun2::new("wrapping_add", i64::wrapping_add),
+                Fun2::new("wrapping_mul", i64::wrapping_mul),
+                Fun1::new("wrapping_neg", i64::wrapping_neg),
+                Elem::new("zero", 0),
+                Elem::new("one", 1),
+            );
+        }
+
+        mod gf5_field {
+            type = u8;
+            set = Set::new("u8 as GF(5)", dice::u8(0..=4));
+            field(
+                Set::new("u8 as GF(5) without zero", dice::u8(1..=4)),
+                Fun2::infix("+", |x, y| (x + y) % 5),
+                Fun2::infix("*", |x, y| (x * y) % 5),
+                Fun1::new("-", |x: u8| (5 - x) % 5),
+                Fun1::new("inv", |x: u8| x.pow(3) % 5),
+                Elem::new("zero", 0),
+                Elem::new("one", 1),
+            );
+        }
+
+        mod string_partial_equivalence {
+            type = String;
+            set = Set::new("String", dice::string(dice::char(), ..));
+            partial_equivalence(Fun2::infix("==", |x, y| x == y));
+        }
+
+        mod u8_partial_order {
+            type = u8;
+            set = Set::new("u8 as bit set", dice::u8(..));
+            partial_order(Fun2::new("is_subset", |x: u8, y: u8| x & y == x));
+        }
+
+        mod u8_total_order {
+            type = u8;
+            set = Set::new("u8", dice::u8(..));
+            total_order(Fun2::infix("<=", |x, y| x <= y));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the generated tests don't match the laws")]
+    fn select_law_panics_if_tests_do_not_match_laws() {
+        let vars = Vars::new(
+            "i64",
+            [Elem::new("x", 1), Elem::new("y", 2), Elem::new("z", 3)],
+        );
+        let op = Fun2::infix("+", |x, y| x + y);
+        let zero = Elem::new("zero", 0);
+
+        crate::__private::select_law("op_is_associative", &["op_is_associative"], || {
+            props::algebra::monoid_laws(vars, op, zero)
+        });
+    }
+
+    #[test]
+    fn select_law_builds_only_the_selected_law() {
+        let vars = Vars::new(
+            "i64",
+            [Elem::new("x", 1), Elem::new("y", 2), Elem::new("z", 3)],
+        );
+        let op = Fun2::infix("+", |x, y| x + y);
+        let zero = Elem::new("zero", 0);
+        let ids = ["op_is_associative", "e_is_identity_elem_of_op"];
+
+        let law = crate::__private::select_law("e_is_identity_elem_of_op", &ids, || {
+            let laws = props::algebra::monoid_laws(vars, op, zero);
+            assert_eq!(laws.len(), 1);
+            laws
+        });
+
+        assert_eq!(law.id, "e_is_identity_elem_of_op");
+        assert_eq!(law.name, "Is `zero` identity element of `+`?");
+    }
+
+    #[test]
+    fn associative_has_same_hints_as_props() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let op = Fun2::infix("+", |x, y| x + y);
+
+            let (_, expected) = hints::collect(|| props::binop::associative(vars, op.as_ref()));
+            let (_, actual) = hints::collect(|| {
+                hint_section!("Is `{}` associative?", op.name);
+                let [a, b, c] = vars.eval();
+                prop!(op(op(a, b), c) == op(a, op(b, c)));
+            });
+
+            assert_eq!(expected, actual);
+        })
+    }
+
+    #[test]
+    fn left_inverse_elem_has_same_hints_as_props() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let op = Fun2::infix("+", |x, y| x + y);
+            let inv = Fun1::new("-", |x: i64| -x);
+
+            let (_, expected) =
+                hints::collect(|| props::binop::left_inverse_elem(vars, op.as_ref(), inv.as_ref()));
+            let (_, actual) = hints::collect(|| {
+                hint_section!(
+                    "Does `{}` return left inverse element regarding to `{}`?",
+                    inv.name,
+                    op.name,
+                );
+                let [a, b] = vars.eval();
+                prop!(op(b, op(inv(a), a)) == b);
+            });
+
+            assert_eq!(expected, actual);
+        })
+    }
+
+    #[test]
+    fn antisymmetric_has_same_hints_as_props() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("u8", dice::u8(..));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let rel = Fun2::infix("<", |x, y| x < y);
+
+            let (_, expected) = hints::collect(|| props::binrel::antisymmetric(vars, rel.as_ref()));
+            let (_, actual) = hints::collect(|| {
+                hint_section!("Is `{}` antisymmetric?", rel.name);
+                let [a, b] = vars.eval();
+                prop!(a != b && rel(a, b) --> !rel(b, a));
+            });
+
+            assert_eq!(expected, actual);
+        })
+    }
+
+    #[test]
+    fn comparison_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("u8", dice::u8(..));
+            let [a, b] = fate.roll(set.vars(["x", "y"])).eval();
+            let max = Fun2::new("max", u8::max);
+            let min = Fun2::new("min", u8::min);
+            prop!(min(a, b) <= max(a, b));
+            prop!(a < b || a >= b);
+            prop!((a > b) <-> (b < a));
+            prop!(max(a, b) != min(a, b) --> a != b);
+        })
+    }
+}
@@ -13,5 +13,8 @@ pub use eq::*;
 mod ord;
 pub use ord::*;
 
+mod approx;
+pub use approx::*;
+
 mod assert;
 pub use assert::*;
@@ -0,0 +1,146 @@
+use crate::{Eval, Fun2, Fun2Label};
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Mul, Sub};
+
+/// A floating-point type whose values can be compared with [`approx_eq`] and [`approx_le`].
+///
+/// It's implemented for [`f32`] and [`f64`].
+pub trait ApproxFloat:
+    Debug + Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_approx_float {
+    ($float:ident) => {
+        impl ApproxFloat for $float {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn abs(self) -> Self {
+                $float::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $float::sqrt(self)
+            }
+        }
+    };
+}
+
+impl_approx_float!(f32);
+impl_approx_float!(f64);
+
+/// The values are approximately equal.
+///
+/// The absolute difference of the values must not exceed `tolerance`, or `tolerance` relative
+/// to the larger magnitude if it's greater than 1.
+pub fn approx_eq<L1, L2, F>(
+    lhs: Eval<L1, &F>,
+    rhs: Eval<L2, &F>,
+    tolerance: F,
+) -> Eval<Fun2Label<'static, L1, L2>, bool>
+where
+    L1: Display + Copy,
+    L2: Display + Copy,
+    F: ApproxFloat,
+{
+    approx_eq_fun(tolerance).eval_once(lhs, rhs)
+}
+
+/// The first value is less than or approximately equal to the second value.
+///
+/// The first value may exceed the second value by `tolerance`, or by `tolerance` relative to the
+/// larger magnitude if it's greater than 1.
+pub fn approx_le<L1, L2, F>(
+    lhs: Eval<L1, &F>,
+    rhs: Eval<L2, &F>,
+    tolerance: F,
+) -> Eval<Fun2Label<'static, L1, L2>, bool>
+where
+    L1: Display + Copy,
+    L2: Display + Copy,
+    F: ApproxFloat,
+{
+    approx_le_fun(tolerance).eval_once(lhs, rhs)
+}
+
+/// Returns the function of [`approx_eq`].
+pub(crate) fn approx_eq_fun<F: ApproxFloat>(
+    tolerance: F,
+) -> Fun2<'static, impl Fn(&F, &F) -> bool> {
+    Fun2::infix("≈", move |l: &F, r: &F| {
+        l == r || (*l - *r).abs() <= scaled_tolerance(*l, *r, tolerance)
+    })
+}
+
+/// Returns the function of [`approx_le`].
+pub(crate) fn approx_le_fun<F: ApproxFloat>(
+    tolerance: F,
+) -> Fun2<'static, impl Fn(&F, &F) -> bool> {
+    Fun2::infix("≲", move |l: &F, r: &F| {
+        l <= r || *l - *r <= scaled_tolerance(*l, *r, tolerance)
+    })
+}
+
+fn scaled_tolerance<F: ApproxFloat>(l: F, r: F, tolerance: F) -> F {
+    let magnitude = if l.abs() > r.abs() { l.abs() } else { r.abs() };
+    if magnitude > F::ONE {
+        tolerance * magnitude
+    } else {
+        tolerance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{ops, Fun1, Set};
+
+    #[test]
+    fn approx_eq_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("f32 ∩ [0,1000000]", dice::f32(0.0..=1_000_000.0));
+            let [x] = fate.roll(set.vars(["x"])).eval();
+            let sqrt = Fun1::new("√", f32::sqrt);
+            let lhs = sqrt.eval(x) * sqrt.eval(x);
+
+            ops::assert(ops::approx_eq(lhs.as_ref(), x.as_ref(), 1e-6));
+        })
+    }
+
+    #[test]
+    fn approx_le_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("f64 ∩ [-1000,1000]", dice::f64(-1000.0..=1000.0));
+            let [x, y, z] = fate.roll(set.vars(["x", "y", "z"])).eval();
+            let lhs = (x + y) * z;
+            let rhs = x * z + y * z;
+
+            ops::assert(ops::approx_le(lhs.as_ref(), rhs.as_ref(), 1e-9));
+        })
+    }
+
+    #[test]
+    fn approx_eq_and_approx_le_reject_values_out_of_tolerance() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("f64 ∩ [-1000,1000]", dice::f64(-1000.0..=1000.0));
+            let [x] = fate.roll(set.vars(["x"])).eval();
+            let shift = Fun1::new("shift", |x: f64| x + 0.01 * x.abs().max(1.0));
+            let y = shift.eval(x);
+
+            ops::assert(!ops::approx_eq(x.as_ref(), y.as_ref(), 1e-3));
+            ops::assert(!ops::approx_le(y.as_ref(), x.as_ref(), 1e-3));
+        })
+    }
+}
@@ -1,11 +1,17 @@
-use crate::Eval;
+use crate::{check, Eval};
 use std::fmt::Display;
 
 /// Asserts that `assertion` is true.
 ///
 /// # Panics
 ///
-/// Panics if `assertion` is false.
+/// Panics if `assertion` is false. Inside of [`check`] the failed assertion will be returned
+/// as [`PropFailure`] instead.
+///
+/// [`check`]: crate::check
+/// [`PropFailure`]: crate::PropFailure
 pub fn assert<L: Display + Copy>(assertion: Eval<L, bool>) {
-    assert!(assertion.value, "assertion failed: {}", assertion.label);
+    if !assertion.value {
+        check::fail(assertion.label.to_string());
+    }
 }
@@ -8,7 +8,7 @@ where
     L2: Display + Copy,
     V: Debug + PartialEq,
 {
-    Fun2::infix("==", |l, r| l == r).eval_once(lhs, rhs)
+    eq_fun().eval_once(lhs, rhs)
 }
 
 /// The values are not equal based on [`PartialEq`].
@@ -18,5 +18,15 @@ where
     L2: Display + Copy,
     V: Debug + PartialEq,
 {
-    Fun2::infix("!=", |l, r| l != r).eval_once(lhs, rhs)
+    ne_fun().eval_once(lhs, rhs)
+}
+
+/// Returns the function of [`eq`].
+pub(crate) fn eq_fun<V: PartialEq>() -> Fun2<'static, impl Fn(&V, &V) -> bool> {
+    Fun2::infix("==", |l: &V, r: &V| l == r)
+}
+
+/// Returns the function of [`ne`].
+pub(crate) fn ne_fun<V: PartialEq>() -> Fun2<'static, impl Fn(&V, &V) -> bool> {
+    Fun2::infix("!=", |l: &V, r: &V| l != r)
 }
@@ -7,7 +7,7 @@ pub fn not<L>(b: Eval<L, bool>) -> Eval<Fun1Label<'static, L>, bool>
 where
     L: Display + Copy,
 {
-    Fun1::new("!", bool::not).eval_once(b)
+    not_fun().eval_once(b)
 }
 
 /// Logical conjunction.
@@ -19,7 +19,7 @@ where
     L1: Display + Copy,
     L2: Display + Copy,
 {
-    Fun2::infix("&&", |l, r| l && r).eval_once(lhs, rhs)
+    and_fun().eval_once(lhs, rhs)
 }
 
 /// Logical disjunction.
@@ -31,7 +31,7 @@ where
     L1: Display + Copy,
     L2: Display + Copy,
 {
-    Fun2::infix("||", |l, r| l || r).eval_once(lhs, rhs)
+    or_fun().eval_once(lhs, rhs)
 }
 
 /// Logical implication.
@@ -43,7 +43,7 @@ where
     L1: Display + Copy,
     L2: Display + Copy,
 {
-    Fun2::infix("-->", |a: bool, c| !a || c).eval_once(antecedent, consequent)
+    implies_fun().eval_once(antecedent, consequent)
 }
 
 /// Logical equivalence.
@@ -55,5 +55,30 @@ where
     L1: Display + Copy,
     L2: Display + Copy,
 {
-    Fun2::infix("<->", |l, r| l == r).eval_once(lhs, rhs)
+    iff_fun().eval_once(lhs, rhs)
+}
+
+/// Returns the function of [`not`].
+pub(crate) fn not_fun() -> Fun1<'static, impl Fn(bool) -> bool> {
+    Fun1::new("!", bool::not)
+}
+
+/// Returns the function of [`and`].
+pub(crate) fn and_fun() -> Fun2<'static, impl Fn(bool, bool) -> bool> {
+    Fun2::infix("&&", |l, r| l && r)
+}
+
+/// Returns the function of [`or`].
+pub(crate) fn or_fun() -> Fun2<'static, impl Fn(bool, bool) -> bool> {
+    Fun2::infix("||", |l, r| l || r)
+}
+
+/// Returns the function of [`implies`].
+pub(crate) fn implies_fun() -> Fun2<'static, impl Fn(bool, bool) -> bool> {
+    Fun2::infix("-->", |a: bool, c| !a || c)
+}
+
+/// Returns the function of [`iff`].
+pub(crate) fn iff_fun() -> Fun2<'static, impl Fn(bool, bool) -> bool> {
+    Fun2::infix("<->", |l, r| l == r)
 }
@@ -8,7 +8,7 @@ where
     L2: Display + Copy,
     V: Debug + PartialOrd,
 {
-    Fun2::infix("<", V::lt).eval_once(lhs, rhs)
+    lt_fun().eval_once(lhs, rhs)
 }
 
 /// The first value is less than or equal to the second value based on [`PartialOrd`].
@@ -18,7 +18,7 @@ where
     L2: Display + Copy,
     V: Debug + PartialOrd,
 {
-    Fun2::infix("<=", V::le).eval_once(lhs, rhs)
+    le_fun().eval_once(lhs, rhs)
 }
 
 /// The first value is greater than the second value based on [`PartialOrd`].
@@ -28,7 +28,7 @@ where
     L2: Display + Copy,
     V: Debug + PartialOrd,
 {
-    Fun2::infix(">", V::gt).eval_once(lhs, rhs)
+    gt_fun().eval_once(lhs, rhs)
 }
 
 /// The first value is greater than or equal to the second value based on [`PartialOrd`].
@@ -38,5 +38,25 @@ where
     L2: Display + Copy,
     V: Debug + PartialOrd,
 {
-    Fun2::infix(">=", V::ge).eval_once(lhs, rhs)
+    ge_fun().eval_once(lhs, rhs)
+}
+
+/// Returns the function of [`lt`].
+pub(crate) fn lt_fun<V: PartialOrd>() -> Fun2<'static, impl Fn(&V, &V) -> bool> {
+    Fun2::infix("<", V::lt)
+}
+
+/// Returns the function of [`le`].
+pub(crate) fn le_fun<V: PartialOrd>() -> Fun2<'static, impl Fn(&V, &V) -> bool> {
+    Fun2::infix("<=", V::le)
+}
+
+/// Returns the function of [`gt`].
+pub(crate) fn gt_fun<V: PartialOrd>() -> Fun2<'static, impl Fn(&V, &V) -> bool> {
+    Fun2::infix(">", V::gt)
+}
+
+/// Returns the function of [`ge`].
+pub(crate) fn ge_fun<V: PartialOrd>() -> Fun2<'static, impl Fn(&V, &V) -> bool> {
+    Fun2::infix(">=", V::ge)
 }
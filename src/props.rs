@@ -7,3 +7,26 @@ pub mod binop;
 pub mod binrel;
 
 pub mod algebra;
+
+pub mod int;
+
+pub mod ops_coherence;
+
+pub mod metric;
+
+pub mod functor;
+
+pub mod monad;
+
+pub mod iter;
+
+pub mod model;
+
+pub mod linearizable;
+
+pub mod crdt;
+
+pub mod codec;
+
+#[cfg(feature = "num-traits")]
+pub mod num;
@@ -2,11 +2,19 @@
 //!
 //! [algebraic structures]: https://en.wikipedia.org/wiki/Algebraic_structure
 
-use dicetest::hint_section;
+use dicetest::{hint, hint_section};
 use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg};
+use std::rc::Rc;
 
-use crate::props::binop::{associative, commutative, distributive, identity_elem, inverse_elem};
-use crate::{Elem, Fun1, Fun2, Vars};
+use crate::law::{self, Law};
+use crate::ops::ApproxFloat;
+use crate::props::binop::{
+    associative_law, distributive_law, identity_elem_law, inverse_elem_law, no_zero_divisors_law,
+};
+use crate::props::binrel::{compatible_law, non_negative_closed_law, total_order_laws_rc};
+use crate::props::fun::commutative_law;
+use crate::{ops, Elem, Eval, Fun1, Fun2, Vars};
 
 /// Asserts that `(vars.set, op)` is a [semigroup].
 ///
@@ -14,6 +22,7 @@ use crate::{Elem, Fun1, Fun2, Vars};
 /// - `op` is associative  ([`associative`])
 ///
 /// [semigroup]: https://en.wikipedia.org/wiki/Semigroup
+/// [`associative`]: crate::props::binop::associative
 pub fn semigroup<S, O>(vars: Vars<S, 3>, op: Fun2<O>)
 where
     S: Debug + Clone + PartialEq,
@@ -21,7 +30,9 @@ where
 {
     hint_section!("Is `({}, {})` a semigroup?", vars.set, op.name);
 
-    associative(vars, op);
+    for law in semigroup_laws(vars, op) {
+        law.assert();
+    }
 }
 
 /// Asserts that `(vars.set, op, e)` is a [monoid].
@@ -31,6 +42,7 @@ where
 /// - `e` is the identity element of `op` ([`identity_elem`])
 ///
 /// [monoid]: https://en.wikipedia.org/wiki/Monoid
+/// [`identity_elem`]: crate::props::binop::identity_elem
 pub fn monoid<S, O>(vars: Vars<S, 3>, op: Fun2<O>, e: Elem<S>)
 where
     S: Debug + Clone + PartialEq,
@@ -38,12 +50,9 @@ where
 {
     hint_section!("Is `({}, {}, {})` a monoid?", vars.set, op.name, e.name,);
 
-    let [a, b, c] = vars.elems;
-    let vars_1 = Vars::new(vars.set, [a.clone()]);
-    let vars_3 = Vars::new(vars.set, [a, b, c]);
-
-    semigroup(vars_3, op.as_ref());
-    identity_elem(vars_1, op, e)
+    for law in monoid_laws(vars, op, e) {
+        law.assert();
+    }
 }
 
 /// Asserts that `(vars.set, op, inv, e)` is a [group].
@@ -53,6 +62,7 @@ where
 /// - `inv` returns the inverse elements regarding to `op` ([`inverse_elem`])
 ///
 /// [group]: https://en.wikipedia.org/wiki/Group_(mathematics)
+/// [`inverse_elem`]: crate::props::binop::inverse_elem
 pub fn group<S, O, I>(vars: Vars<S, 3>, op: Fun2<O>, inv: Fun1<I>, e: Elem<S>)
 where
     S: Debug + Clone + PartialEq,
@@ -67,12 +77,9 @@ where
         e.name,
     );
 
-    let [a, b, c] = vars.elems;
-    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
-    let vars_3 = Vars::new(vars.set, [a, b, c]);
-
-    monoid(vars_3, op.as_ref(), e);
-    inverse_elem(vars_2, op, inv);
+    for law in group_laws(vars, op, inv, e) {
+        law.assert();
+    }
 }
 
 /// Asserts that `(vars.set, op, inv, e)` is an [abelian group].
@@ -82,6 +89,7 @@ where
 /// - `op` is commutative ([`commutative`])
 ///
 /// [abelian group]: https://en.wikipedia.org/wiki/Abelian_group
+/// [`commutative`]: crate::props::binop::commutative
 pub fn abelian_group<S, O, I>(vars: Vars<S, 3>, op: Fun2<O>, inv: Fun1<I>, e: Elem<S>)
 where
     S: Debug + Clone + PartialEq,
@@ -96,12 +104,9 @@ where
         e.name,
     );
 
-    let [a, b, c] = vars.elems;
-    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
-    let vars_3 = Vars::new(vars.set, [a, b, c]);
-
-    group(vars_3, op.as_ref(), inv, e);
-    commutative(vars_2, op);
+    for law in abelian_group_laws(vars, op, inv, e) {
+        law.assert();
+    }
 }
 
 /// Asserts that `(vars.set, add, mul, neg, zero, one)` is a [ring].
@@ -112,6 +117,7 @@ where
 /// - `mul` is distributive over `add` ([`distributive`])
 ///
 /// [ring]: https://en.wikipedia.org/wiki/Ring_(mathematics)
+/// [`distributive`]: crate::props::binop::distributive
 pub fn ring<S, A, N, M>(
     vars: Vars<S, 3>,
     add: Fun2<A>,
@@ -135,9 +141,9 @@ pub fn ring<S, A, N, M>(
         one.name,
     );
 
-    abelian_group(vars.clone(), add.as_ref(), neg, zero);
-    monoid(vars.clone(), mul.as_ref(), one);
-    distributive(vars, add, mul);
+    for law in ring_laws(vars, add, mul, neg, zero, one) {
+        law.assert();
+    }
 }
 
 /// Asserts that `(vars.set, add, mul, neg, zero, one)` is a [commutative ring].
@@ -147,6 +153,7 @@ pub fn ring<S, A, N, M>(
 /// - `mul` is commutative ([`commutative`])
 ///
 /// [commutative ring]: https://en.wikipedia.org/wiki/Commutative_ring
+/// [`commutative`]: crate::props::binop::commutative
 pub fn commutative_ring<S, A, M, N>(
     vars: Vars<S, 3>,
     add: Fun2<A>,
@@ -170,12 +177,9 @@ pub fn commutative_ring<S, A, M, N>(
         one.name,
     );
 
-    let [a, b, c] = vars.elems;
-    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
-    let vars_3 = Vars::new(vars.set, [a, b, c]);
-
-    ring(vars_3, add, mul.as_ref(), neg, zero, one);
-    commutative(vars_2, mul);
+    for law in commutative_ring_laws(vars, add, mul, neg, zero, one) {
+        law.assert();
+    }
 }
 
 /// Asserts that `(vars.set, add, mul, neg, inv, zero, one)` is a [field].
@@ -186,6 +190,7 @@ pub fn commutative_ring<S, A, M, N>(
 /// to `mul` ([`inverse_elem`])
 ///
 /// [field]: https://en.wikipedia.org/wiki/Field_(mathematics)
+/// [`inverse_elem`]: crate::props::binop::inverse_elem
 #[allow(clippy::too_many_arguments)]
 pub fn field<S, A, M, N, I>(
     vars: Vars<S, 3>,
@@ -214,91 +219,3075 @@ pub fn field<S, A, M, N, I>(
         one.name,
     );
 
-    commutative_ring(vars, add, mul.as_ref(), neg, zero, one);
-    inverse_elem(non_zero_vars, mul, inv);
+    for law in field_laws(vars, non_zero_vars, add, mul, neg, inv, zero, one) {
+        law.assert();
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use dicetest::prelude::*;
-
-    use crate::{props, Elem, Fun1, Fun2, Set};
+/// Asserts that `(vars.set, add, mul, neg, zero, one)` is an [integral domain].
+///
+/// It must hold:
+/// - `(vars.set, add, mul, neg, zero, one)` is a commutative ring ([`commutative_ring`])
+/// - For `a`, `b` of `non_zero_vars.set` the result of `mul(a, b)` is not `zero`
+///   ([`no_zero_divisors`])
+///
+/// [integral domain]: https://en.wikipedia.org/wiki/Integral_domain
+/// [`no_zero_divisors`]: crate::props::binop::no_zero_divisors
+#[allow(clippy::too_many_arguments)]
+pub fn integral_domain<S, A, M, N>(
+    vars: Vars<S, 3>,
+    non_zero_vars: Vars<S, 2>,
+    add: Fun2<A>,
+    mul: Fun2<M>,
+    neg: Fun1<N>,
+    zero: Elem<S>,
+    one: Elem<S>,
+) where
+    S: Debug + Clone + PartialEq,
+    A: Fn(S, S) -> S,
+    M: Fn(S, S) -> S,
+    N: Fn(S) -> S,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {}, {}, {})` an integral domain?",
+        vars.set,
+        add.name,
+        mul.name,
+        neg.name,
+        zero.name,
+        one.name,
+    );
 
-    #[test]
-    fn semigroup_example() {
-        Dicetest::once().run(|mut fate| {
-            let set = Set::new("u64", dice::u64(..=1000));
-            let vars = fate.roll(set.vars(["x", "y", "z"]));
-            let op = Fun2::infix("+", |x, y| x + y);
-            props::algebra::semigroup(vars, op);
-        })
+    for law in integral_domain_laws(vars, non_zero_vars, add, mul, neg, zero, one) {
+        law.assert();
     }
+}
 
-    #[test]
-    fn monoid_example() {
-        Dicetest::once().run(|mut fate| {
-            let set = Set::new("u64", dice::u64(..=1000));
-            let vars = fate.roll(set.vars(["x", "y", "z"]));
-            let op = Fun2::infix("+", |x, y| x + y);
-            let e = Elem::new("zero", 0);
-            props::algebra::monoid(vars, op, e);
-        })
-    }
+/// Asserts that the functions `div` and `rem` are a [Euclidean division] regarding to the
+/// function `norm`.
+///
+/// For all `a` of `vars.set` and `b` of `non_zero_vars.set` it must hold:
+/// - `add(mul(div(a, b), b), rem(a, b)) == a`
+/// - `(rem(a, b) == zero) || (norm(rem(a, b)) < norm(b))`
+///
+/// [Euclidean division]: https://en.wikipedia.org/wiki/Euclidean_domain#Definition
+#[allow(clippy::too_many_arguments)]
+pub fn euclidean_division<S, T, A, M, D, R, N>(
+    vars: Vars<S, 1>,
+    non_zero_vars: Vars<S, 1>,
+    add: Fun2<A>,
+    mul: Fun2<M>,
+    div: Fun2<D>,
+    rem: Fun2<R>,
+    norm: Fun1<N>,
+    zero: Elem<S>,
+) where
+    S: Debug + Clone + PartialEq,
+    T: Debug + PartialOrd,
+    A: FnOnce(S, S) -> S,
+    M: FnOnce(S, S) -> S,
+    D: FnOnce(S, S) -> S,
+    R: FnOnce(S, S) -> S,
+    N: Fn(S) -> T,
+{
+    hint_section!("{}", euclidean_division_name(div.name, rem.name, norm.name),);
 
-    #[test]
-    fn group_example() {
-        Dicetest::once().run(|mut fate| {
-            let set = Set::new("i64", dice::i64(-1000..=1000));
-            let vars = fate.roll(set.vars(["x", "y", "z"]));
-            let op = Fun2::infix("+", |x, y| x + y);
-            let inv = Fun1::new("-", |x: i64| -x);
-            let e = Elem::new("zero", 0);
-            props::algebra::group(vars, op, inv, e);
-        })
+    let [a] = vars.eval();
+    let [b] = non_zero_vars.eval();
+    let zero = zero.eval();
+
+    let q = div.eval_once(a.clone(), b.clone());
+    let r = rem.eval_once(a.clone(), b.clone());
+
+    ops::assert(ops::eq(
+        add.eval_once(mul.eval_once(q, b.clone()), r.clone())
+            .as_ref(),
+        a.as_ref(),
+    ));
+    ops::assert(ops::or(
+        ops::eq(r.as_ref(), zero.as_ref()),
+        ops::lt(norm.eval(r).as_ref(), norm.eval(b).as_ref()),
+    ));
+}
+
+/// Asserts that `(vars.set, add, mul, neg, zero, one)` is a [Euclidean domain] with the
+/// Euclidean division `div` and `rem` regarding to the function `norm`.
+///
+/// It must hold:
+/// - `(vars.set, add, mul, neg, zero, one)` is an integral domain ([`integral_domain`])
+/// - For `a` of `vars.set` and `b` of `non_zero_vars.set` the functions `div` and `rem` are a
+///   Euclidean division regarding to `norm` ([`euclidean_division`])
+/// - For `a`, `b` of `non_zero_vars.set` it holds `norm(a) <= norm(mul(a, b))`
+///
+/// [Euclidean domain]: https://en.wikipedia.org/wiki/Euclidean_domain
+#[allow(clippy::too_many_arguments)]
+pub fn euclidean_domain<S, T, A, M, N, D, R, F>(
+    vars: Vars<S, 3>,
+    non_zero_vars: Vars<S, 2>,
+    add: Fun2<A>,
+    mul: Fun2<M>,
+    neg: Fun1<N>,
+    div: Fun2<D>,
+    rem: Fun2<R>,
+    norm: Fun1<F>,
+    zero: Elem<S>,
+    one: Elem<S>,
+) where
+    S: Debug + Clone + PartialEq,
+    T: Debug + PartialOrd,
+    A: Fn(S, S) -> S,
+    M: Fn(S, S) -> S,
+    N: Fn(S) -> S,
+    D: Fn(S, S) -> S,
+    R: Fn(S, S) -> S,
+    F: Fn(S) -> T,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {}, {}, {})` a Euclidean domain with `{}`, `{}` and `{}`?",
+        vars.set,
+        add.name,
+        mul.name,
+        neg.name,
+        zero.name,
+        one.name,
+        div.name,
+        rem.name,
+        norm.name,
+    );
+
+    let laws = euclidean_domain_laws(
+        vars,
+        non_zero_vars,
+        add,
+        mul,
+        neg,
+        div,
+        rem,
+        norm,
+        zero,
+        one,
+    );
+    for law in laws {
+        law.assert();
     }
+}
 
-    #[test]
-    fn abelian_group_example() {
-        Dicetest::once().run(|mut fate| {
-            let set = Set::new("i64", dice::i64(-1000..=1000));
-            let vars = fate.roll(set.vars(["x", "y", "z"]));
-            let op = Fun2::infix("+", |x, y| x + y);
-            let inv = Fun1::new("-", |x: i64| -x);
-            let e = Elem::new("zero", 0);
-            props::algebra::abelian_group(vars, op, inv, e);
-        })
+fn non_decreasing_norm<S, T, M, N>(non_zero_vars: Vars<S, 2>, mul: Fun2<M>, norm: Fun1<N>)
+where
+    S: Debug + Clone,
+    T: Debug + PartialOrd,
+    M: FnOnce(S, S) -> S,
+    N: Fn(S) -> T,
+{
+    hint_section!("{}", non_decreasing_norm_name(norm.name, mul.name));
+
+    let [a, b] = non_zero_vars.eval();
+
+    ops::assert(ops::le(
+        norm.eval(a.clone()).as_ref(),
+        norm.eval(mul.eval_once(a, b)).as_ref(),
+    ));
+}
+
+/// Asserts that the function `gcd` returns the [greatest common divisor] regarding to the
+/// divisibility relation `divides`.
+///
+/// For all `a`, `b`, `d` of `vars.set` it must hold:
+/// - `divides(gcd(a, b), a) && divides(gcd(a, b), b)`
+/// - `(divides(d, a) && divides(d, b)) -> divides(d, gcd(a, b))`
+///
+/// The second law is only checked if `d` is a common divisor of `a` and `b`, hence `vars.set`
+/// should contain elements with common divisors.
+///
+/// [greatest common divisor]: https://en.wikipedia.org/wiki/Greatest_common_divisor
+pub fn greatest_common_divisor<S, G, D>(vars: Vars<S, 3>, gcd: Fun2<G>, divides: Fun2<D>)
+where
+    S: Debug + Clone,
+    G: Fn(S, S) -> S,
+    D: Fn(S, S) -> bool,
+{
+    hint_section!("{}", greatest_common_divisor_name(gcd.name, divides.name),);
+
+    let [a, b, d] = vars.eval();
+
+    let g = gcd.eval(a.clone(), b.clone());
+    ops::assert(ops::and(
+        divides.eval(g.clone(), a.clone()),
+        divides.eval(g.clone(), b.clone()),
+    ));
+    ops::assert(ops::implies(
+        ops::and(divides.eval(d.clone(), a), divides.eval(d.clone(), b)),
+        divides.eval(d, g),
+    ));
+}
+
+/// Asserts that `(vars.set, add, mul, neg, zero, one)` is a [GCD domain] with the greatest
+/// common divisor `gcd` regarding to the divisibility relation `divides`.
+///
+/// It must hold:
+/// - `(vars.set, add, mul, neg, zero, one)` is an integral domain ([`integral_domain`])
+/// - `gcd` returns the greatest common divisor ([`greatest_common_divisor`])
+///
+/// [GCD domain]: https://en.wikipedia.org/wiki/GCD_domain
+#[allow(clippy::too_many_arguments)]
+pub fn gcd_domain<S, A, M, N, G, D>(
+    vars: Vars<S, 3>,
+    non_zero_vars: Vars<S, 2>,
+    add: Fun2<A>,
+    mul: Fun2<M>,
+    neg: Fun1<N>,
+    gcd: Fun2<G>,
+    divides: Fun2<D>,
+    zero: Elem<S>,
+    one: Elem<S>,
+) where
+    S: Debug + Clone + PartialEq,
+    A: Fn(S, S) -> S,
+    M: Fn(S, S) -> S,
+    N: Fn(S) -> S,
+    G: Fn(S, S) -> S,
+    D: Fn(S, S) -> bool,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {}, {}, {})` a GCD domain with `{}` and `{}`?",
+        vars.set,
+        add.name,
+        mul.name,
+        neg.name,
+        zero.name,
+        one.name,
+        gcd.name,
+        divides.name,
+    );
+
+    let laws = gcd_domain_laws(vars, non_zero_vars, add, mul, neg, gcd, divides, zero, one);
+    for law in laws {
+        law.assert();
     }
+}
 
-    #[test]
-    fn ring_example() {
-        Dicetest::once().run(|mut fate| {
-            let set = Set::new("i64", dice::i64(-1000..=1000));
-            let vars = fate.roll(set.vars(["x", "y", "z"]));
-            let add = Fun2::infix("+", |x, y| x + y);
-            let mul = Fun2::infix("*", |x, y| x * y);
-            let neg = Fun1::new("-", |x: i64| -x);
-            let zero = Elem::new("zero", 0);
-            let one = Elem::new("one", 1);
-            props::algebra::ring(vars, add, mul, neg, zero, one);
-        })
+/// Asserts that `(vars.set, op, inv, le, e)` is an [ordered group].
+///
+/// It must hold:
+/// - `(vars.set, op, inv, e)` is an abelian group ([`abelian_group`])
+/// - `le` is a total order ([`total_order`])
+/// - `le` is compatible with `op` ([`compatible`])
+///
+/// [ordered group]: https://en.wikipedia.org/wiki/Linearly_ordered_group
+/// [`total_order`]: crate::props::binrel::total_order
+/// [`compatible`]: crate::props::binrel::compatible
+pub fn ordered_group<S, O, I, R>(
+    vars: Vars<S, 3>,
+    op: Fun2<O>,
+    inv: Fun1<I>,
+    le: Fun2<R>,
+    e: Elem<S>,
+) where
+    S: Debug + Clone + PartialEq,
+    O: Fn(S, S) -> S,
+    I: Fn(S) -> S,
+    R: Fn(S, S) -> bool,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {}, {})` an ordered group?",
+        vars.set,
+        op.name,
+        inv.name,
+        le.name,
+        e.name,
+    );
+
+    for law in ordered_group_laws(vars, op, inv, le, e) {
+        law.assert();
     }
+}
 
-    #[test]
-    fn commutative_ring_example() {
-        Dicetest::once().run(|mut fate| {
-            let set = Set::new("i64", dice::i64(-1000..=1000));
-            let vars = fate.roll(set.vars(["x", "y", "z"]));
-            let add = Fun2::infix("+", |x, y| x + y);
-            let mul = Fun2::infix("*", |x, y| x * y);
-            let neg = Fun1::new("-", |x: i64| -x);
-            let zero = Elem::new("zero", 0);
-            let one = Elem::new("one", 1);
-            props::algebra::commutative_ring(vars, add, mul, neg, zero, one);
-        })
+/// Asserts that `(vars.set, add, mul, neg, le, zero, one)` is an [ordered ring].
+///
+/// It must hold:
+/// - `(vars.set, add, mul, neg, zero, one)` is a commutative ring ([`commutative_ring`])
+/// - `le` is a total order ([`total_order`])
+/// - `le` is compatible with `add` ([`compatible`])
+/// - The elements not less than `zero` are closed under `mul` ([`non_negative_closed`])
+///
+/// [ordered ring]: https://en.wikipedia.org/wiki/Ordered_ring
+/// [`total_order`]: crate::props::binrel::total_order
+/// [`compatible`]: crate::props::binrel::compatible
+/// [`non_negative_closed`]: crate::props::binrel::non_negative_closed
+#[allow(clippy::too_many_arguments)]
+pub fn ordered_ring<S, A, M, N, R>(
+    vars: Vars<S, 3>,
+    add: Fun2<A>,
+    mul: Fun2<M>,
+    neg: Fun1<N>,
+    le: Fun2<R>,
+    zero: Elem<S>,
+    one: Elem<S>,
+) where
+    S: Debug + Clone + PartialEq,
+    A: Fn(S, S) -> S,
+    M: Fn(S, S) -> S,
+    N: Fn(S) -> S,
+    R: Fn(S, S) -> bool,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {}, {}, {}, {})` an ordered ring?",
+        vars.set,
+        add.name,
+        mul.name,
+        neg.name,
+        le.name,
+        zero.name,
+        one.name,
+    );
+
+    for law in ordered_ring_laws(vars, add, mul, neg, le, zero, one) {
+        law.assert();
     }
+}
 
-    #[test]
-    fn field_example() {
-        // Are there any fields in libstd?
+/// Asserts that `(vars.set, add, mul, neg, inv, le, zero, one)` is an [ordered field].
+///
+/// It must hold:
+/// - `(vars.set, add, mul, neg, inv, zero, one)` is a field ([`field`])
+/// - `le` is a total order ([`total_order`])
+/// - `le` is compatible with `add` ([`compatible`])
+/// - The elements not less than `zero` are closed under `mul` ([`non_negative_closed`])
+///
+/// [ordered field]: https://en.wikipedia.org/wiki/Ordered_field
+/// [`total_order`]: crate::props::binrel::total_order
+/// [`compatible`]: crate::props::binrel::compatible
+/// [`non_negative_closed`]: crate::props::binrel::non_negative_closed
+#[allow(clippy::too_many_arguments)]
+pub fn ordered_field<S, A, M, N, I, R>(
+    vars: Vars<S, 3>,
+    non_zero_vars: Vars<S, 2>,
+    add: Fun2<A>,
+    mul: Fun2<M>,
+    neg: Fun1<N>,
+    inv: Fun1<I>,
+    le: Fun2<R>,
+    zero: Elem<S>,
+    one: Elem<S>,
+) where
+    S: Debug + Clone + PartialEq,
+    A: Fn(S, S) -> S,
+    M: Fn(S, S) -> S,
+    N: Fn(S) -> S,
+    I: Fn(S) -> S,
+    R: Fn(S, S) -> bool,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {}, {}, {}, {}, {})` an ordered field?",
+        vars.set,
+        add.name,
+        mul.name,
+        neg.name,
+        inv.name,
+        le.name,
+        zero.name,
+        one.name,
+    );
+
+    let laws = ordered_field_laws(vars, non_zero_vars, add, mul, neg, inv, le, zero, one);
+    for law in laws {
+        law.assert();
+    }
+}
+
+/// Asserts that the function `smul` is a [scalar multiplication] of the vectors of `vars.set`
+/// with the scalars of `scalar_vars.set`.
+///
+/// For all `u`, `v` of `vars.set` and `k`, `l` of `scalar_vars.set` it must hold:
+/// - `smul(k, add(u, v)) == add(smul(k, u), smul(k, v))`
+/// - `smul(scalar_add(k, l), u) == add(smul(k, u), smul(l, u))`
+/// - `smul(scalar_mul(k, l), u) == smul(k, smul(l, u))`
+/// - `smul(scalar_one, u) == u`
+///
+/// [scalar multiplication]: https://en.wikipedia.org/wiki/Scalar_multiplication
+#[allow(clippy::too_many_arguments)]
+pub fn scalar_multiplication<V, K, A, B, M, P>(
+    vars: Vars<V, 2>,
+    scalar_vars: Vars<K, 2>,
+    add: Fun2<A>,
+    scalar_add: Fun2<B>,
+    scalar_mul: Fun2<M>,
+    scalar_one: Elem<K>,
+    smul: Fun2<P>,
+) where
+    V: Debug + Clone + PartialEq,
+    K: Debug + Clone,
+    A: Fn(V, V) -> V,
+    B: FnOnce(K, K) -> K,
+    M: FnOnce(K, K) -> K,
+    P: Fn(K, V) -> V,
+{
+    hint_section!(
+        "{}",
+        scalar_multiplication_name(
+            smul.name,
+            add.name,
+            scalar_add.name,
+            scalar_mul.name,
+            scalar_one.name,
+        ),
+    );
+
+    let [u, v] = vars.eval();
+    let [k, l] = scalar_vars.eval();
+    let scalar_one = scalar_one.eval();
+
+    ops::assert(ops::eq(
+        smul.eval(k.clone(), add.eval(u.clone(), v.clone()))
+            .as_ref(),
+        add.eval(smul.eval(k.clone(), u.clone()), smul.eval(k.clone(), v))
+            .as_ref(),
+    ));
+    ops::assert(ops::eq(
+        smul.eval(scalar_add.eval_once(k.clone(), l.clone()), u.clone())
+            .as_ref(),
+        add.eval(
+            smul.eval(k.clone(), u.clone()),
+            smul.eval(l.clone(), u.clone()),
+        )
+        .as_ref(),
+    ));
+    ops::assert(ops::eq(
+        smul.eval(scalar_mul.eval_once(k.clone(), l.clone()), u.clone())
+            .as_ref(),
+        smul.eval(k, smul.eval(l, u.clone())).as_ref(),
+    ));
+    ops::assert(ops::eq(
+        smul.eval(scalar_one, u.clone()).as_ref(),
+        u.as_ref(),
+    ));
+}
+
+/// Asserts that `(vars.set, add, neg, zero)` is a [module] over the scalars of
+/// `scalar_vars.set` with the scalar multiplication `smul`.
+///
+/// It must hold:
+/// - `(scalar_vars.set, scalar_add, scalar_mul, scalar_neg, scalar_zero, scalar_one)` is a ring
+///   ([`ring`])
+/// - `add` is commutative ([`commutative`])
+/// - `zero` is the identity element of `add` ([`identity_elem`])
+/// - `neg` returns the inverse element regarding to `add` ([`inverse_elem`])
+/// - `smul` is a scalar multiplication ([`scalar_multiplication`])
+///
+/// The associativity of `add` requires three vectors and can be asserted with
+/// [`abelian_group`].
+///
+/// [module]: https://en.wikipedia.org/wiki/Module_(mathematics)
+/// [`commutative`]: crate::props::binop::commutative
+/// [`identity_elem`]: crate::props::binop::identity_elem
+/// [`inverse_elem`]: crate::props::binop::inverse_elem
+#[allow(clippy::too_many_arguments)]
+pub fn module<V, K, A, N, B, M, O, P>(
+    vars: Vars<V, 2>,
+    scalar_vars: Vars<K, 3>,
+    add: Fun2<A>,
+    neg: Fun1<N>,
+    zero: Elem<V>,
+    scalar_add: Fun2<B>,
+    scalar_mul: Fun2<M>,
+    scalar_neg: Fun1<O>,
+    scalar_zero: Elem<K>,
+    scalar_one: Elem<K>,
+    smul: Fun2<P>,
+) where
+    V: Debug + Clone + PartialEq,
+    K: Debug + Clone + PartialEq,
+    A: Fn(V, V) -> V,
+    N: Fn(V) -> V,
+    B: Fn(K, K) -> K,
+    M: Fn(K, K) -> K,
+    O: Fn(K) -> K,
+    P: Fn(K, V) -> V,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {})` a module over `{}` with `{}`?",
+        vars.set,
+        add.name,
+        neg.name,
+        zero.name,
+        scalar_vars.set,
+        smul.name,
+    );
+
+    let laws = module_laws(
+        vars,
+        scalar_vars,
+        add,
+        neg,
+        zero,
+        scalar_add,
+        scalar_mul,
+        scalar_neg,
+        scalar_zero,
+        scalar_one,
+        smul,
+    );
+    for law in laws {
+        law.assert();
+    }
+}
+
+/// Asserts that `(vars.set, add, neg, zero)` is a [vector space] over the field of
+/// `scalar_vars.set` with the scalar multiplication `smul`.
+///
+/// It must hold:
+/// - `(scalar_vars.set, scalar_add, scalar_mul, scalar_neg, scalar_inv, scalar_zero, scalar_one)`
+///   is a field ([`field`])
+/// - `(vars.set, add, neg, zero)` is a module over `scalar_vars.set` ([`module`]), except that
+///   the scalars are already asserted to be a field
+///
+/// [vector space]: https://en.wikipedia.org/wiki/Vector_space
+#[allow(clippy::too_many_arguments)]
+pub fn vector_space<V, K, A, N, B, M, O, I, P>(
+    vars: Vars<V, 2>,
+    scalar_vars: Vars<K, 3>,
+    non_zero_scalar_vars: Vars<K, 2>,
+    add: Fun2<A>,
+    neg: Fun1<N>,
+    zero: Elem<V>,
+    scalar_add: Fun2<B>,
+    scalar_mul: Fun2<M>,
+    scalar_neg: Fun1<O>,
+    scalar_inv: Fun1<I>,
+    scalar_zero: Elem<K>,
+    scalar_one: Elem<K>,
+    smul: Fun2<P>,
+) where
+    V: Debug + Clone + PartialEq,
+    K: Debug + Clone + PartialEq,
+    A: Fn(V, V) -> V,
+    N: Fn(V) -> V,
+    B: Fn(K, K) -> K,
+    M: Fn(K, K) -> K,
+    O: Fn(K) -> K,
+    I: Fn(K) -> K,
+    P: Fn(K, V) -> V,
+{
+    hint_section!(
+        "Is `({}, {}, {}, {})` a vector space over `{}` with `{}`?",
+        vars.set,
+        add.name,
+        neg.name,
+        zero.name,
+        scalar_vars.set,
+        smul.name,
+    );
+
+    let laws = vector_space_laws(
+        vars,
+        scalar_vars,
+        non_zero_scalar_vars,
+        add,
+        neg,
+        zero,
+        scalar_add,
+        scalar_mul,
+        scalar_neg,
+        scalar_inv,
+        scalar_zero,
+        scalar_one,
+        smul,
+    );
+    for law in laws {
+        law.assert();
+    }
+}
+
+/// Asserts that the function `dot` is an [inner product] of the vector space
+/// `(vars.set, add, smul, zero)` over the real numbers.
+///
+/// The real numbers are approximated by a floating-point type (see [`ApproxFloat`]). The results
+/// are compared with [`ops::approx_eq`] and [`ops::approx_le`] using `tolerance`.
+///
+/// It must hold:
+/// - For all `u`, `v` of `vars.set`: `dot(u, v) ≈ dot(v, u)`
+/// - For all `u`, `v`, `w` of `vars.set` and `k` of `scalar_vars.set`:
+///   `dot(add(smul(k, u), v), w) ≈ k * dot(u, w) + dot(v, w)`
+/// - For all `u` of `vars.set`: `0 ≲ dot(u, u)` and `(dot(u, u) == 0) <-> (u == zero)`
+/// - `dot` fulfills the Cauchy–Schwarz inequality ([`cauchy_schwarz`])
+///
+/// Together with symmetry, the linearity in the first argument implies bilinearity.
+/// `vars.set` must not contain non-zero vectors whose inner product with themselves underflows to
+/// zero.
+///
+/// [inner product]: https://en.wikipedia.org/wiki/Inner_product_space
+#[allow(clippy::too_many_arguments)]
+pub fn inner_product_space<V, K, A, M, D>(
+    vars: Vars<V, 3>,
+    scalar_vars: Vars<K, 1>,
+    add: Fun2<A>,
+    smul: Fun2<M>,
+    dot: Fun2<D>,
+    zero: Elem<V>,
+    tolerance: K,
+) where
+    V: Debug + Clone + PartialEq,
+    K: ApproxFloat,
+    A: Fn(V, V) -> V,
+    M: Fn(K, V) -> V,
+    D: Fn(V, V) -> K,
+{
+    hint_section!(
+        "Is `{}` an inner product of `({}, {}, {}, {})`?",
+        dot.name,
+        vars.set,
+        add.name,
+        smul.name,
+        zero.name,
+    );
+
+    for law in inner_product_space_laws(vars, scalar_vars, add, smul, dot, zero, tolerance) {
+        law.assert();
+    }
+}
+
+/// Asserts that the function `dot` fulfills the [Cauchy–Schwarz inequality].
+///
+/// The real numbers are approximated by a floating-point type (see [`ApproxFloat`]). The results
+/// are compared with [`ops::approx_le`] using `tolerance`.
+///
+/// For all `u`, `v` of `vars.set` it must hold:
+/// - `abs(dot(u, v)) ≲ √(dot(u, u)) * √(dot(v, v))`
+///
+/// [Cauchy–Schwarz inequality]: https://en.wikipedia.org/wiki/Cauchy%E2%80%93Schwarz_inequality
+pub fn cauchy_schwarz<V, K, D>(vars: Vars<V, 2>, dot: Fun2<D>, tolerance: K)
+where
+    V: Debug + Clone,
+    K: ApproxFloat,
+    D: Fn(V, V) -> K,
+{
+    hint_section!("{}", cauchy_schwarz_name(dot.name));
+
+    let [u, v] = vars.eval();
+    let abs = abs_fun();
+    let sqrt = sqrt_fun();
+
+    let lhs = abs.eval(dot.eval(u.clone(), v.clone()));
+    let rhs = sqrt.eval(dot.eval(u.clone(), u)) * sqrt.eval(dot.eval(v.clone(), v));
+
+    ops::assert(ops::approx_le(lhs.as_ref(), rhs.as_ref(), tolerance));
+}
+
+fn dot_symmetric<V, K, D>(vars: Vars<V, 2>, dot: Fun2<D>, tolerance: K)
+where
+    V: Debug + Clone,
+    K: ApproxFloat,
+    D: Fn(V, V) -> K,
+{
+    hint_section!("{}", dot_symmetric_name(dot.name));
+
+    let [u, v] = vars.eval();
+
+    ops::assert(ops::approx_eq(
+        dot.eval(u.clone(), v.clone()).as_ref(),
+        dot.eval(v, u).as_ref(),
+        tolerance,
+    ));
+}
+
+fn dot_linear<V, K, A, M, D>(
+    vars: Vars<V, 3>,
+    scalar_vars: Vars<K, 1>,
+    add: Fun2<A>,
+    smul: Fun2<M>,
+    dot: Fun2<D>,
+    tolerance: K,
+) where
+    V: Debug + Clone,
+    K: ApproxFloat,
+    A: FnOnce(V, V) -> V,
+    M: FnOnce(K, V) -> V,
+    D: Fn(V, V) -> K,
+{
+    hint_section!("{}", dot_linear_name(dot.name));
+
+    let [u, v, w] = vars.eval();
+    let [k] = scalar_vars.eval();
+
+    let lhs = dot.eval(
+        add.eval_once(smul.eval_once(k, u.clone()), v.clone()),
+        w.clone(),
+    );
+    let rhs = k * dot.eval(u, w.clone()) + dot.eval(v, w);
+
+    ops::assert(ops::approx_eq(lhs.as_ref(), rhs.as_ref(), tolerance));
+}
+
+fn dot_positive_definite<V, K, D>(vars: Vars<V, 1>, dot: Fun2<D>, zero: Elem<V>, tolerance: K)
+where
+    V: Debug + Clone + PartialEq,
+    K: ApproxFloat,
+    D: Fn(V, V) -> K,
+{
+    hint_section!("{}", dot_positive_definite_name(dot.name));
+
+    let [u] = vars.eval();
+    let zero = zero.eval();
+    let zero_scalar = zero_scalar().eval();
+    let uu = dot.eval(u.clone(), u.clone());
+
+    ops::assert(ops::approx_le(zero_scalar.as_ref(), uu.as_ref(), tolerance));
+    ops::assert(ops::iff(
+        ops::eq(uu.as_ref(), zero_scalar.as_ref()),
+        ops::eq(u.as_ref(), zero.as_ref()),
+    ));
+}
+
+/// Asserts that the function `norm` is a [norm] of the vector space
+/// `(vars.set, add, smul, zero)` over the real numbers.
+///
+/// The real numbers are approximated by a floating-point type (see [`ApproxFloat`]). The results
+/// are compared with [`ops::approx_eq`] and [`ops::approx_le`] using `tolerance`.
+///
+/// For all `u`, `v` of `vars.set` and `k` of `scalar_vars.set` it must hold:
+/// - `norm(add(u, v)) ≲ norm(u) + norm(v)`
+/// - `norm(smul(k, u)) ≈ abs(k) * norm(u)`
+/// - `(norm(u) == 0) <-> (u == zero)`
+///
+/// `vars.set` must not contain non-zero vectors whose norm underflows to zero.
+///
+/// [norm]: https://en.wikipedia.org/wiki/Normed_vector_space
+#[allow(clippy::too_many_arguments)]
+pub fn normed_space<V, K, A, M, N>(
+    vars: Vars<V, 2>,
+    scalar_vars: Vars<K, 1>,
+    add: Fun2<A>,
+    smul: Fun2<M>,
+    norm: Fun1<N>,
+    zero: Elem<V>,
+    tolerance: K,
+) where
+    V: Debug + Clone + PartialEq,
+    K: ApproxFloat,
+    A: FnOnce(V, V) -> V,
+    M: FnOnce(K, V) -> V,
+    N: Fn(V) -> K,
+{
+    hint_section!(
+        "Is `{}` a norm of `({}, {}, {}, {})`?",
+        norm.name,
+        vars.set,
+        add.name,
+        smul.name,
+        zero.name,
+    );
+
+    let [u, v] = vars.eval();
+    let [k] = scalar_vars.eval();
+    let zero = zero.eval();
+    let zero_scalar = zero_scalar().eval();
+    let abs = abs_fun();
+
+    let norm_u = norm.eval(u.clone());
+    let norm_v = norm.eval(v.clone());
+
+    let lhs = norm.eval(add.eval_once(u.clone(), v));
+    let rhs = norm_u + norm_v;
+    ops::assert(ops::approx_le(lhs.as_ref(), rhs.as_ref(), tolerance));
+
+    let lhs = norm.eval(smul.eval_once(k, u.clone()));
+    let rhs = abs.eval(k) * norm_u;
+    ops::assert(ops::approx_eq(lhs.as_ref(), rhs.as_ref(), tolerance));
+
+    ops::assert(ops::iff(
+        ops::eq(norm_u.as_ref(), zero_scalar.as_ref()),
+        ops::eq(u.as_ref(), zero.as_ref()),
+    ));
+}
+
+/// Asserts that the function `act` is a [monoid action] of the monoid `(vars.set, op, e)` on
+/// the elements of `target_vars.set`.
+///
+/// The monoid itself is assumed to be valid (see [`monoid`]).
+///
+/// For all `g`, `h` of `vars.set` and `x` of `target_vars.set` it must hold:
+/// - `act(e, x) == x`
+/// - `act(op(g, h), x) == act(g, act(h, x))`
+///
+/// [monoid action]: https://en.wikipedia.org/wiki/Semigroup_action
+pub fn monoid_action<G, X, O, A>(
+    vars: Vars<G, 2>,
+    target_vars: Vars<X, 1>,
+    op: Fun2<O>,
+    e: Elem<G>,
+    act: Fun2<A>,
+) where
+    G: Debug + Clone,
+    X: Debug + Clone + PartialEq,
+    O: Fn(G, G) -> G,
+    A: Fn(G, X) -> X,
+{
+    hint_section!(
+        "Is `{}` an action of `({}, {}, {})` on `{}`?",
+        act.name,
+        vars.set,
+        op.name,
+        e.name,
+        target_vars.set,
+    );
+
+    for law in monoid_action_laws(vars, target_vars, op, e, act) {
+        law.assert();
+    }
+}
+
+/// Asserts that the function `act` is a [group action] of the group `(vars.set, op, inv, e)` on
+/// the elements of `target_vars.set`.
+///
+/// The group itself is assumed to be valid (see [`group`]).
+///
+/// It must hold:
+/// - `act` is a monoid action of `(vars.set, op, e)` ([`monoid_action`])
+/// - For all `g` of `vars.set` and `x` of `target_vars.set`: `act(inv(g), act(g, x)) == x`
+///
+/// [group action]: https://en.wikipedia.org/wiki/Group_action
+pub fn group_action<G, X, O, I, A>(
+    vars: Vars<G, 2>,
+    target_vars: Vars<X, 1>,
+    op: Fun2<O>,
+    inv: Fun1<I>,
+    e: Elem<G>,
+    act: Fun2<A>,
+) where
+    G: Debug + Clone,
+    X: Debug + Clone + PartialEq,
+    O: Fn(G, G) -> G,
+    I: Fn(G) -> G,
+    A: Fn(G, X) -> X,
+{
+    hint_section!(
+        "Is `{}` an action of `({}, {}, {}, {})` on `{}`?",
+        act.name,
+        vars.set,
+        op.name,
+        inv.name,
+        e.name,
+        target_vars.set,
+    );
+
+    for law in group_action_laws(vars, target_vars, op, inv, e, act) {
+        law.assert();
+    }
+}
+
+fn trivial_action<G, X, A>(target_vars: Vars<X, 1>, e: Elem<G>, act: Fun2<A>)
+where
+    G: Debug + Clone,
+    X: Debug + Clone + PartialEq,
+    A: FnOnce(G, X) -> X,
+{
+    hint_section!("{}", trivial_action_name(act.name, e.name));
+
+    let [x] = target_vars.eval();
+    let e = e.eval();
+
+    ops::assert(ops::eq(act.eval_once(e, x.clone()).as_ref(), x.as_ref()));
+}
+
+fn compatible_action<G, X, O, A>(
+    vars: Vars<G, 2>,
+    target_vars: Vars<X, 1>,
+    op: Fun2<O>,
+    act: Fun2<A>,
+) where
+    G: Debug + Clone,
+    X: Debug + Clone + PartialEq,
+    O: FnOnce(G, G) -> G,
+    A: Fn(G, X) -> X,
+{
+    hint_section!("{}", compatible_action_name(act.name, op.name));
+
+    let [g, h] = vars.eval();
+    let [x] = target_vars.eval();
+
+    ops::assert(ops::eq(
+        act.eval(op.eval_once(g.clone(), h.clone()), x.clone())
+            .as_ref(),
+        act.eval(g, act.eval(h, x)).as_ref(),
+    ));
+}
+
+fn undoing_action<G, X, I, A>(vars: Vars<G, 1>, target_vars: Vars<X, 1>, inv: Fun1<I>, act: Fun2<A>)
+where
+    G: Debug + Clone,
+    X: Debug + Clone + PartialEq,
+    I: FnOnce(G) -> G,
+    A: Fn(G, X) -> X,
+{
+    hint_section!("{}", undoing_action_name(act.name, inv.name));
+
+    let [g] = vars.eval();
+    let [x] = target_vars.eval();
+
+    ops::assert(ops::eq(
+        act.eval(inv.eval_once(g.clone()), act.eval(g, x.clone()))
+            .as_ref(),
+        x.as_ref(),
+    ));
+}
+
+/// Asserts that the function `concat_all` is consistent with the [fold] of the monoid
+/// `(vars.set, op, e)`.
+///
+/// The monoid itself is assumed to be valid (see [`monoid`]).
+///
+/// For all `xs` of `vars.set` it must hold:
+/// - `concat_all(xs) == fold(op)(xs, e)`
+///
+/// [fold]: https://en.wikipedia.org/wiki/Fold_(higher-order_function)
+pub fn fold_consistent<S, O, C>(vars: Vars<Vec<S>, 1>, op: Fun2<O>, e: Elem<S>, concat_all: Fun1<C>)
+where
+    S: Debug + Clone + PartialEq,
+    O: Fn(S, S) -> S,
+    C: FnOnce(Vec<S>) -> S,
+{
+    hint_section!(
+        "Is `{}` equal to the fold with `{}` and `{}`?",
+        concat_all.name,
+        op.name,
+        e.name,
+    );
+
+    let [xs] = vars.eval();
+    let e = e.eval();
+    let fold_name = format!("fold({})", op.name);
+    let fold = Fun2::new(&fold_name, |xs: Vec<S>, e| xs.into_iter().fold(e, op.f()));
+
+    ops::assert(ops::eq(
+        concat_all.eval_once(xs.clone()).as_ref(),
+        fold.eval_once(xs, e).as_ref(),
+    ));
+}
+
+/// Asserts that the function `f` is invariant under splitting its argument, i.e. that `f` is a
+/// [homomorphism] from the concatenation of vectors to `op`.
+///
+/// The split position `i` is taken from `split_vars` modulo `len(zs) + 1`.
+///
+/// For all `zs` of `vars.set` and `i` of `split_vars.set` it must hold:
+/// - `f(zs) == op(f(zs[..i]), f(zs[i..]))`
+///
+/// [homomorphism]: https://en.wikipedia.org/wiki/Monoid#Monoid_homomorphisms
+pub fn split_invariant<S, T, F, O>(
+    vars: Vars<Vec<S>, 1>,
+    split_vars: Vars<usize, 1>,
+    f: Fun1<F>,
+    op: Fun2<O>,
+) where
+    S: Debug + Clone,
+    T: Debug + PartialEq,
+    F: Fn(Vec<S>) -> T,
+    O: FnOnce(T, T) -> T,
+{
+    hint_section!("Is `{}` invariant under splitting?", f.name);
+
+    let [zs] = vars.eval();
+    let [i] = split_vars.eval();
+    let i = i.value % (zs.value.len() + 1);
+    hint!("split `{}` at {}", zs.label, i);
+
+    let xs_label = format!("{}[..{}]", zs.label, i);
+    let ys_label = format!("{}[{}..]", zs.label, i);
+    let xs = Eval {
+        label: xs_label.as_str(),
+        value: zs.value[..i].to_vec(),
+    };
+    let ys = Eval {
+        label: ys_label.as_str(),
+        value: zs.value[i..].to_vec(),
+    };
+
+    ops::assert(ops::eq(
+        f.eval(zs).as_ref(),
+        op.eval_once(f.eval(xs), f.eval(ys)).as_ref(),
+    ));
+}
+
+/// Asserts that `(vars.set, +, -, zero)` is an [abelian group] based on the implementations of
+/// [`Add`] and [`Neg`].
+///
+/// This is a shortcut for [`abelian_group`].
+///
+/// [abelian group]: https://en.wikipedia.org/wiki/Abelian_group
+pub fn abelian_group_of<S>(vars: Vars<S, 3>, zero: Elem<S>)
+where
+    S: Debug + Clone + PartialEq + Add<Output = S> + Neg<Output = S>,
+{
+    let add = Fun2::infix("+", S::add);
+    let neg = Fun1::new("-", S::neg);
+
+    abelian_group(vars, add, neg, zero);
+}
+
+/// Asserts that `(vars.set, +, *, -, zero, one)` is a [ring] based on the implementations of
+/// [`Add`], [`Mul`] and [`Neg`].
+///
+/// This is a shortcut for [`ring`].
+///
+/// [ring]: https://en.wikipedia.org/wiki/Ring_(mathematics)
+pub fn ring_of<S>(vars: Vars<S, 3>, zero: Elem<S>, one: Elem<S>)
+where
+    S: Debug + Clone + PartialEq + Add<Output = S> + Mul<Output = S> + Neg<Output = S>,
+{
+    let add = Fun2::infix("+", S::add);
+    let mul = Fun2::infix("*", S::mul);
+    let neg = Fun1::new("-", S::neg);
+
+    ring(vars, add, mul, neg, zero, one);
+}
+
+/// Asserts that `(vars.set, +, *, -, zero, one)` is a [commutative ring] based on the
+/// implementations of [`Add`], [`Mul`] and [`Neg`].
+///
+/// This is a shortcut for [`commutative_ring`].
+///
+/// [commutative ring]: https://en.wikipedia.org/wiki/Commutative_ring
+pub fn commutative_ring_of<S>(vars: Vars<S, 3>, zero: Elem<S>, one: Elem<S>)
+where
+    S: Debug + Clone + PartialEq + Add<Output = S> + Mul<Output = S> + Neg<Output = S>,
+{
+    let add = Fun2::infix("+", S::add);
+    let mul = Fun2::infix("*", S::mul);
+    let neg = Fun1::new("-", S::neg);
+
+    commutative_ring(vars, add, mul, neg, zero, one);
+}
+
+/// Returns the laws of a [semigroup] (see [`semigroup`]).
+///
+/// [semigroup]: https://en.wikipedia.org/wiki/Semigroup
+pub fn semigroup_laws<'a, S, O>(vars: Vars<'a, S, 3>, op: Fun2<'a, O>) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    semigroup_laws_rc(vars, ("op", &Rc::new(op)))
+}
+
+/// Returns the laws of a [monoid] (see [`monoid`]).
+///
+/// [monoid]: https://en.wikipedia.org/wiki/Monoid
+pub fn monoid_laws<'a, S, O>(vars: Vars<'a, S, 3>, op: Fun2<'a, O>, e: Elem<'a, S>) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    monoid_laws_rc(vars, ("op", &Rc::new(op)), ("e", e))
+}
+
+/// Returns the laws of a [group] (see [`group`]).
+///
+/// [group]: https://en.wikipedia.org/wiki/Group_(mathematics)
+pub fn group_laws<'a, S, O, I>(
+    vars: Vars<'a, S, 3>,
+    op: Fun2<'a, O>,
+    inv: Fun1<'a, I>,
+    e: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+{
+    group_laws_rc(vars, ("op", &Rc::new(op)), ("inv", &Rc::new(inv)), ("e", e))
+}
+
+/// Returns the laws of an [abelian group] (see [`abelian_group`]).
+///
+/// [abelian group]: https://en.wikipedia.org/wiki/Abelian_group
+pub fn abelian_group_laws<'a, S, O, I>(
+    vars: Vars<'a, S, 3>,
+    op: Fun2<'a, O>,
+    inv: Fun1<'a, I>,
+    e: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+{
+    abelian_group_laws_rc(vars, ("op", &Rc::new(op)), ("inv", &Rc::new(inv)), ("e", e))
+}
+
+/// Returns the laws of a [ring] (see [`ring`]).
+///
+/// [ring]: https://en.wikipedia.org/wiki/Ring_(mathematics)
+pub fn ring_laws<'a, S, A, M, N>(
+    vars: Vars<'a, S, 3>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+{
+    ring_laws_rc(
+        vars,
+        ("add", &Rc::new(add)),
+        ("mul", &Rc::new(mul)),
+        ("neg", &Rc::new(neg)),
+        ("zero", zero),
+        ("one", one),
+    )
+}
+
+/// Returns the laws of a [commutative ring] (see [`commutative_ring`]).
+///
+/// [commutative ring]: https://en.wikipedia.org/wiki/Commutative_ring
+pub fn commutative_ring_laws<'a, S, A, M, N>(
+    vars: Vars<'a, S, 3>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+{
+    commutative_ring_laws_rc(
+        vars,
+        ("add", &Rc::new(add)),
+        ("mul", &Rc::new(mul)),
+        ("neg", &Rc::new(neg)),
+        ("zero", zero),
+        ("one", one),
+    )
+}
+
+/// Returns the laws of a [field] (see [`field`]).
+///
+/// [field]: https://en.wikipedia.org/wiki/Field_(mathematics)
+#[allow(clippy::too_many_arguments)]
+pub fn field_laws<'a, S, A, M, N, I>(
+    vars: Vars<'a, S, 3>,
+    non_zero_vars: Vars<'a, S, 2>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    inv: Fun1<'a, I>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+{
+    field_laws_rc(
+        vars,
+        non_zero_vars,
+        ("add", &Rc::new(add)),
+        ("mul", &Rc::new(mul)),
+        ("neg", &Rc::new(neg)),
+        ("inv", &Rc::new(inv)),
+        ("zero", zero),
+        ("one", one),
+    )
+}
+
+/// Returns the laws of an [integral domain] (see [`integral_domain`]).
+///
+/// [integral domain]: https://en.wikipedia.org/wiki/Integral_domain
+#[allow(clippy::too_many_arguments)]
+pub fn integral_domain_laws<'a, S, A, M, N>(
+    vars: Vars<'a, S, 3>,
+    non_zero_vars: Vars<'a, S, 2>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+{
+    integral_domain_laws_rc(
+        vars,
+        non_zero_vars,
+        ("add", &Rc::new(add)),
+        ("mul", &Rc::new(mul)),
+        ("neg", &Rc::new(neg)),
+        ("zero", zero),
+        ("one", one),
+    )
+}
+
+/// Returns the laws of a [Euclidean domain] (see [`euclidean_domain`]).
+///
+/// [Euclidean domain]: https://en.wikipedia.org/wiki/Euclidean_domain
+#[allow(clippy::too_many_arguments)]
+pub fn euclidean_domain_laws<'a, S, T, A, M, N, D, R, F>(
+    vars: Vars<'a, S, 3>,
+    non_zero_vars: Vars<'a, S, 2>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    div: Fun2<'a, D>,
+    rem: Fun2<'a, R>,
+    norm: Fun1<'a, F>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    T: Debug + PartialOrd,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+    D: Fn(S, S) -> S + 'a,
+    R: Fn(S, S) -> S + 'a,
+    F: Fn(S) -> T + 'a,
+{
+    let add = ("add", &Rc::new(add));
+    let mul = ("mul", &Rc::new(mul));
+    let zero = ("zero", zero);
+
+    let [a, b, c] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+    let [d, e] = non_zero_vars.elems;
+    let non_zero_vars_1 = Vars::new(non_zero_vars.set, [d.clone()]);
+    let non_zero_vars_2 = Vars::new(non_zero_vars.set, [d, e]);
+    let norm = ("norm", &Rc::new(norm));
+
+    let mut laws = integral_domain_laws_rc(
+        vars_3,
+        non_zero_vars_2.clone(),
+        add,
+        mul,
+        ("neg", &Rc::new(neg)),
+        zero.clone(),
+        ("one", one),
+    );
+    laws.extend(euclidean_division_law(
+        vars_1,
+        non_zero_vars_1,
+        add,
+        mul,
+        ("div", &Rc::new(div)),
+        ("rem", &Rc::new(rem)),
+        norm,
+        zero,
+    ));
+    laws.extend(non_decreasing_norm_law(non_zero_vars_2, mul, norm));
+    laws
+}
+
+/// Returns the laws of a [GCD domain] (see [`gcd_domain`]).
+///
+/// [GCD domain]: https://en.wikipedia.org/wiki/GCD_domain
+#[allow(clippy::too_many_arguments)]
+pub fn gcd_domain_laws<'a, S, A, M, N, G, D>(
+    vars: Vars<'a, S, 3>,
+    non_zero_vars: Vars<'a, S, 2>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    gcd: Fun2<'a, G>,
+    divides: Fun2<'a, D>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+    G: Fn(S, S) -> S + 'a,
+    D: Fn(S, S) -> bool + 'a,
+{
+    let mut laws = integral_domain_laws_rc(
+        vars.clone(),
+        non_zero_vars,
+        ("add", &Rc::new(add)),
+        ("mul", &Rc::new(mul)),
+        ("neg", &Rc::new(neg)),
+        ("zero", zero),
+        ("one", one),
+    );
+    laws.extend(greatest_common_divisor_law(
+        vars,
+        ("gcd", &Rc::new(gcd)),
+        ("divides", &Rc::new(divides)),
+    ));
+    laws
+}
+
+/// Returns the laws of an [ordered group] (see [`ordered_group`]).
+///
+/// [ordered group]: https://en.wikipedia.org/wiki/Linearly_ordered_group
+pub fn ordered_group_laws<'a, S, O, I, R>(
+    vars: Vars<'a, S, 3>,
+    op: Fun2<'a, O>,
+    inv: Fun1<'a, I>,
+    le: Fun2<'a, R>,
+    e: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    let op = ("op", &Rc::new(op));
+    let le = ("le", &Rc::new(le));
+
+    let mut laws = abelian_group_laws_rc(vars.clone(), op, ("inv", &Rc::new(inv)), ("e", e));
+    laws.extend(total_order_laws_rc(vars.clone(), le));
+    laws.extend(compatible_law(vars, le, op));
+    laws
+}
+
+/// Returns the laws of an [ordered ring] (see [`ordered_ring`]).
+///
+/// [ordered ring]: https://en.wikipedia.org/wiki/Ordered_ring
+#[allow(clippy::too_many_arguments)]
+pub fn ordered_ring_laws<'a, S, A, M, N, R>(
+    vars: Vars<'a, S, 3>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    le: Fun2<'a, R>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    let add = ("add", &Rc::new(add));
+    let mul = ("mul", &Rc::new(mul));
+    let zero = ("zero", zero);
+
+    let mut laws = commutative_ring_laws_rc(
+        vars.clone(),
+        add,
+        mul,
+        ("neg", &Rc::new(neg)),
+        zero.clone(),
+        ("one", one),
+    );
+    laws.extend(order_laws_rc(vars, add, mul, ("le", &Rc::new(le)), zero));
+    laws
+}
+
+/// Returns the laws of an [ordered field] (see [`ordered_field`]).
+///
+/// [ordered field]: https://en.wikipedia.org/wiki/Ordered_field
+#[allow(clippy::too_many_arguments)]
+pub fn ordered_field_laws<'a, S, A, M, N, I, R>(
+    vars: Vars<'a, S, 3>,
+    non_zero_vars: Vars<'a, S, 2>,
+    add: Fun2<'a, A>,
+    mul: Fun2<'a, M>,
+    neg: Fun1<'a, N>,
+    inv: Fun1<'a, I>,
+    le: Fun2<'a, R>,
+    zero: Elem<'a, S>,
+    one: Elem<'a, S>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    let add = ("add", &Rc::new(add));
+    let mul = ("mul", &Rc::new(mul));
+    let zero = ("zero", zero);
+
+    let mut laws = field_laws_rc(
+        vars.clone(),
+        non_zero_vars,
+        add,
+        mul,
+        ("neg", &Rc::new(neg)),
+        ("inv", &Rc::new(inv)),
+        zero.clone(),
+        ("one", one),
+    );
+    laws.extend(order_laws_rc(vars, add, mul, ("le", &Rc::new(le)), zero));
+    laws
+}
+
+/// Returns the laws of a [module] (see [`module`]).
+///
+/// [module]: https://en.wikipedia.org/wiki/Module_(mathematics)
+#[allow(clippy::too_many_arguments)]
+pub fn module_laws<'a, V, K, A, N, B, M, O, P>(
+    vars: Vars<'a, V, 2>,
+    scalar_vars: Vars<'a, K, 3>,
+    add: Fun2<'a, A>,
+    neg: Fun1<'a, N>,
+    zero: Elem<'a, V>,
+    scalar_add: Fun2<'a, B>,
+    scalar_mul: Fun2<'a, M>,
+    scalar_neg: Fun1<'a, O>,
+    scalar_zero: Elem<'a, K>,
+    scalar_one: Elem<'a, K>,
+    smul: Fun2<'a, P>,
+) -> Vec<Law<'a>>
+where
+    V: Debug + Clone + PartialEq + 'a,
+    K: Debug + Clone + PartialEq + 'a,
+    A: Fn(V, V) -> V + 'a,
+    N: Fn(V) -> V + 'a,
+    B: Fn(K, K) -> K + 'a,
+    M: Fn(K, K) -> K + 'a,
+    O: Fn(K) -> K + 'a,
+    P: Fn(K, V) -> V + 'a,
+{
+    let scalar_add = ("scalar_add", &Rc::new(scalar_add));
+    let scalar_mul = ("scalar_mul", &Rc::new(scalar_mul));
+    let scalar_one = ("scalar_one", scalar_one);
+
+    let [k, l, m] = scalar_vars.elems;
+    let scalar_vars_2 = Vars::new(scalar_vars.set, [k.clone(), l.clone()]);
+    let scalar_vars_3 = Vars::new(scalar_vars.set, [k, l, m]);
+
+    let mut laws = ring_laws_rc(
+        scalar_vars_3,
+        scalar_add,
+        scalar_mul,
+        ("scalar_neg", &Rc::new(scalar_neg)),
+        ("scalar_zero", scalar_zero),
+        scalar_one.clone(),
+    );
+    laws.extend(module_laws_rc(
+        vars,
+        scalar_vars_2,
+        ("add", &Rc::new(add)),
+        ("neg", &Rc::new(neg)),
+        ("zero", zero),
+        scalar_add,
+        scalar_mul,
+        scalar_one,
+        ("smul", &Rc::new(smul)),
+    ));
+    laws
+}
+
+/// Returns the laws of a [vector space] (see [`vector_space`]).
+///
+/// [vector space]: https://en.wikipedia.org/wiki/Vector_space
+#[allow(clippy::too_many_arguments)]
+pub fn vector_space_laws<'a, V, K, A, N, B, M, O, I, P>(
+    vars: Vars<'a, V, 2>,
+    scalar_vars: Vars<'a, K, 3>,
+    non_zero_scalar_vars: Vars<'a, K, 2>,
+    add: Fun2<'a, A>,
+    neg: Fun1<'a, N>,
+    zero: Elem<'a, V>,
+    scalar_add: Fun2<'a, B>,
+    scalar_mul: Fun2<'a, M>,
+    scalar_neg: Fun1<'a, O>,
+    scalar_inv: Fun1<'a, I>,
+    scalar_zero: Elem<'a, K>,
+    scalar_one: Elem<'a, K>,
+    smul: Fun2<'a, P>,
+) -> Vec<Law<'a>>
+where
+    V: Debug + Clone + PartialEq + 'a,
+    K: Debug + Clone + PartialEq + 'a,
+    A: Fn(V, V) -> V + 'a,
+    N: Fn(V) -> V + 'a,
+    B: Fn(K, K) -> K + 'a,
+    M: Fn(K, K) -> K + 'a,
+    O: Fn(K) -> K + 'a,
+    I: Fn(K) -> K + 'a,
+    P: Fn(K, V) -> V + 'a,
+{
+    let scalar_add = ("scalar_add", &Rc::new(scalar_add));
+    let scalar_mul = ("scalar_mul", &Rc::new(scalar_mul));
+    let scalar_one = ("scalar_one", scalar_one);
+
+    let [k, l, m] = scalar_vars.elems;
+    let scalar_vars_2 = Vars::new(scalar_vars.set, [k.clone(), l.clone()]);
+    let scalar_vars_3 = Vars::new(scalar_vars.set, [k, l, m]);
+
+    let mut laws = field_laws_rc(
+        scalar_vars_3,
+        non_zero_scalar_vars,
+        scalar_add,
+        scalar_mul,
+        ("scalar_neg", &Rc::new(scalar_neg)),
+        ("scalar_inv", &Rc::new(scalar_inv)),
+        ("scalar_zero", scalar_zero),
+        scalar_one.clone(),
+    );
+    laws.extend(module_laws_rc(
+        vars,
+        scalar_vars_2,
+        ("add", &Rc::new(add)),
+        ("neg", &Rc::new(neg)),
+        ("zero", zero),
+        scalar_add,
+        scalar_mul,
+        scalar_one,
+        ("smul", &Rc::new(smul)),
+    ));
+    laws
+}
+
+/// Returns the laws of an [inner product space] (see [`inner_product_space`]).
+///
+/// [inner product space]: https://en.wikipedia.org/wiki/Inner_product_space
+#[allow(clippy::too_many_arguments)]
+pub fn inner_product_space_laws<'a, V, K, A, M, D>(
+    vars: Vars<'a, V, 3>,
+    scalar_vars: Vars<'a, K, 1>,
+    add: Fun2<'a, A>,
+    smul: Fun2<'a, M>,
+    dot: Fun2<'a, D>,
+    zero: Elem<'a, V>,
+    tolerance: K,
+) -> Vec<Law<'a>>
+where
+    V: Debug + Clone + PartialEq + 'a,
+    K: ApproxFloat + 'a,
+    A: Fn(V, V) -> V + 'a,
+    M: Fn(K, V) -> V + 'a,
+    D: Fn(V, V) -> K + 'a,
+{
+    let dot = ("dot", &Rc::new(dot));
+
+    let [a, b, c] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![
+        dot_symmetric_law(vars_2.clone(), dot, tolerance),
+        dot_linear_law(
+            vars_3,
+            scalar_vars,
+            ("add", &Rc::new(add)),
+            ("smul", &Rc::new(smul)),
+            dot,
+            tolerance,
+        ),
+        dot_positive_definite_law(vars_1, dot, ("zero", zero), tolerance),
+        cauchy_schwarz_law(vars_2, dot, tolerance),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns the laws of a [monoid action] (see [`monoid_action`]).
+///
+/// [monoid action]: https://en.wikipedia.org/wiki/Semigroup_action
+pub fn monoid_action_laws<'a, G, X, O, A>(
+    vars: Vars<'a, G, 2>,
+    target_vars: Vars<'a, X, 1>,
+    op: Fun2<'a, O>,
+    e: Elem<'a, G>,
+    act: Fun2<'a, A>,
+) -> Vec<Law<'a>>
+where
+    G: Debug + Clone + 'a,
+    X: Debug + Clone + PartialEq + 'a,
+    O: Fn(G, G) -> G + 'a,
+    A: Fn(G, X) -> X + 'a,
+{
+    monoid_action_laws_rc(
+        vars,
+        target_vars,
+        ("op", &Rc::new(op)),
+        ("e", e),
+        ("act", &Rc::new(act)),
+    )
+}
+
+/// Returns the laws of a [group action] (see [`group_action`]).
+///
+/// [group action]: https://en.wikipedia.org/wiki/Group_action
+pub fn group_action_laws<'a, G, X, O, I, A>(
+    vars: Vars<'a, G, 2>,
+    target_vars: Vars<'a, X, 1>,
+    op: Fun2<'a, O>,
+    inv: Fun1<'a, I>,
+    e: Elem<'a, G>,
+    act: Fun2<'a, A>,
+) -> Vec<Law<'a>>
+where
+    G: Debug + Clone + 'a,
+    X: Debug + Clone + PartialEq + 'a,
+    O: Fn(G, G) -> G + 'a,
+    I: Fn(G) -> G + 'a,
+    A: Fn(G, X) -> X + 'a,
+{
+    let act = ("act", &Rc::new(act));
+
+    let [g, h] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [g.clone()]);
+    let vars_2 = Vars::new(vars.set, [g, h]);
+
+    let mut laws = monoid_action_laws_rc(
+        vars_2,
+        target_vars.clone(),
+        ("op", &Rc::new(op)),
+        ("e", e),
+        act,
+    );
+    laws.extend(undoing_action_law(
+        vars_1,
+        target_vars,
+        ("inv", &Rc::new(inv)),
+        act,
+    ));
+    laws
+}
+
+fn semigroup_laws_rc<'a, S, O>(vars: Vars<'a, S, 3>, op: (&str, &Rc<Fun2<'a, O>>)) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    associative_law(vars, op).into_iter().collect()
+}
+
+fn monoid_laws_rc<'a, S, O>(
+    vars: Vars<'a, S, 3>,
+    op: (&str, &Rc<Fun2<'a, O>>),
+    e: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    let [a, b, c] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    let mut laws = semigroup_laws_rc(vars_3, op);
+    laws.extend(identity_elem_law(vars_1, op, e));
+    laws
+}
+
+fn group_laws_rc<'a, S, O, I>(
+    vars: Vars<'a, S, 3>,
+    op: (&str, &Rc<Fun2<'a, O>>),
+    inv: (&str, &Rc<Fun1<'a, I>>),
+    e: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+{
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    let mut laws = monoid_laws_rc(vars_3, op, e);
+    laws.extend(inverse_elem_law(vars_2, op, inv));
+    laws
+}
+
+fn abelian_group_laws_rc<'a, S, O, I>(
+    vars: Vars<'a, S, 3>,
+    op: (&str, &Rc<Fun2<'a, O>>),
+    inv: (&str, &Rc<Fun1<'a, I>>),
+    e: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+{
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    let mut laws = group_laws_rc(vars_3, op, inv, e);
+    laws.extend(commutative_law(vars_2, op));
+    laws
+}
+
+fn ring_laws_rc<'a, S, A, M, N>(
+    vars: Vars<'a, S, 3>,
+    add: (&str, &Rc<Fun2<'a, A>>),
+    mul: (&str, &Rc<Fun2<'a, M>>),
+    neg: (&str, &Rc<Fun1<'a, N>>),
+    zero: (&str, Elem<'a, S>),
+    one: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+{
+    let mut laws = abelian_group_laws_rc(vars.clone(), add, neg, zero);
+    laws.extend(monoid_laws_rc(vars.clone(), mul, one));
+    laws.extend(distributive_law(vars, add, mul));
+    laws
+}
+
+fn commutative_ring_laws_rc<'a, S, A, M, N>(
+    vars: Vars<'a, S, 3>,
+    add: (&str, &Rc<Fun2<'a, A>>),
+    mul: (&str, &Rc<Fun2<'a, M>>),
+    neg: (&str, &Rc<Fun1<'a, N>>),
+    zero: (&str, Elem<'a, S>),
+    one: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+{
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    let mut laws = ring_laws_rc(vars_3, add, mul, neg, zero, one);
+    laws.extend(commutative_law(vars_2, mul));
+    laws
+}
+
+#[allow(clippy::too_many_arguments)]
+fn field_laws_rc<'a, S, A, M, N, I>(
+    vars: Vars<'a, S, 3>,
+    non_zero_vars: Vars<'a, S, 2>,
+    add: (&str, &Rc<Fun2<'a, A>>),
+    mul: (&str, &Rc<Fun2<'a, M>>),
+    neg: (&str, &Rc<Fun1<'a, N>>),
+    inv: (&str, &Rc<Fun1<'a, I>>),
+    zero: (&str, Elem<'a, S>),
+    one: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+{
+    let mut laws = commutative_ring_laws_rc(vars, add, mul, neg, zero, one);
+    laws.extend(inverse_elem_law(non_zero_vars, mul, inv));
+    laws
+}
+
+fn integral_domain_laws_rc<'a, S, A, M, N>(
+    vars: Vars<'a, S, 3>,
+    non_zero_vars: Vars<'a, S, 2>,
+    add: (&str, &Rc<Fun2<'a, A>>),
+    mul: (&str, &Rc<Fun2<'a, M>>),
+    neg: (&str, &Rc<Fun1<'a, N>>),
+    zero: (&str, Elem<'a, S>),
+    one: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> S + 'a,
+{
+    let mut laws = commutative_ring_laws_rc(vars, add, mul, neg, zero.clone(), one);
+    laws.extend(no_zero_divisors_law(non_zero_vars, mul, zero));
+    laws
+}
+
+fn order_laws_rc<'a, S, A, M, R>(
+    vars: Vars<'a, S, 3>,
+    add: (&str, &Rc<Fun2<'a, A>>),
+    mul: (&str, &Rc<Fun2<'a, M>>),
+    le: (&str, &Rc<Fun2<'a, R>>),
+    zero: (&str, Elem<'a, S>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    let mut laws = total_order_laws_rc(vars_3.clone(), le);
+    laws.extend(compatible_law(vars_3, le, add));
+    laws.extend(non_negative_closed_law(vars_2, le, mul, zero));
+    laws
+}
+
+#[allow(clippy::too_many_arguments)]
+fn module_laws_rc<'a, V, K, A, N, B, M, P>(
+    vars: Vars<'a, V, 2>,
+    scalar_vars: Vars<'a, K, 2>,
+    add: (&str, &Rc<Fun2<'a, A>>),
+    neg: (&str, &Rc<Fun1<'a, N>>),
+    zero: (&str, Elem<'a, V>),
+    scalar_add: (&str, &Rc<Fun2<'a, B>>),
+    scalar_mul: (&str, &Rc<Fun2<'a, M>>),
+    scalar_one: (&str, Elem<'a, K>),
+    smul: (&str, &Rc<Fun2<'a, P>>),
+) -> Vec<Law<'a>>
+where
+    V: Debug + Clone + PartialEq + 'a,
+    K: Debug + Clone + 'a,
+    A: Fn(V, V) -> V + 'a,
+    N: Fn(V) -> V + 'a,
+    B: Fn(K, K) -> K + 'a,
+    M: Fn(K, K) -> K + 'a,
+    P: Fn(K, V) -> V + 'a,
+{
+    let [a, b] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_2 = Vars::new(vars.set, [a, b]);
+
+    vec![
+        commutative_law(vars_2.clone(), add),
+        identity_elem_law(vars_1, add, zero),
+        inverse_elem_law(vars_2.clone(), add, neg),
+        scalar_multiplication_law(
+            vars_2,
+            scalar_vars,
+            add,
+            scalar_add,
+            scalar_mul,
+            scalar_one,
+            smul,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn monoid_action_laws_rc<'a, G, X, O, A>(
+    vars: Vars<'a, G, 2>,
+    target_vars: Vars<'a, X, 1>,
+    op: (&str, &Rc<Fun2<'a, O>>),
+    e: (&str, Elem<'a, G>),
+    act: (&str, &Rc<Fun2<'a, A>>),
+) -> Vec<Law<'a>>
+where
+    G: Debug + Clone + 'a,
+    X: Debug + Clone + PartialEq + 'a,
+    O: Fn(G, G) -> G + 'a,
+    A: Fn(G, X) -> X + 'a,
+{
+    vec![
+        trivial_action_law(target_vars.clone(), e, act),
+        compatible_action_law(vars, target_vars, op, act),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn euclidean_division_law<'a, S, T, A, M, D, R, N>(
+    vars: Vars<'a, S, 1>,
+    non_zero_vars: Vars<'a, S, 1>,
+    (_, add): (&str, &Rc<Fun2<'a, A>>),
+    (_, mul): (&str, &Rc<Fun2<'a, M>>),
+    (div_id, div): (&str, &Rc<Fun2<'a, D>>),
+    (rem_id, rem): (&str, &Rc<Fun2<'a, R>>),
+    (_, norm): (&str, &Rc<Fun1<'a, N>>),
+    (_, zero): (&str, Elem<'a, S>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    T: Debug + PartialOrd,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+    D: Fn(S, S) -> S + 'a,
+    R: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> T + 'a,
+{
+    let id = format!("{}_and_{}_are_euclidean_division", div_id, rem_id);
+    law::build(id, |id| {
+        let [a] = law::var_names(&vars);
+        let [b] = law::var_names(&non_zero_vars);
+        let name = euclidean_division_name(div.name, rem.name, norm.name);
+        let statement = format!(
+            "{}, {}",
+            law::eq(add.label(mul.label(div.label(a, b), b), rem.label(a, b)), a),
+            law::or(
+                law::eq(rem.label(a, b), zero.name),
+                law::lt(norm.label(rem.label(a, b)), norm.label(b)),
+            ),
+        );
+        let add = Rc::clone(add);
+        let mul = Rc::clone(mul);
+        let div = Rc::clone(div);
+        let rem = Rc::clone(rem);
+        let norm = Rc::clone(norm);
+
+        Law::new(id, name, statement, 2, move || {
+            euclidean_division(
+                vars.clone(),
+                non_zero_vars.clone(),
+                Fun2::as_ref(&add),
+                Fun2::as_ref(&mul),
+                Fun2::as_ref(&div),
+                Fun2::as_ref(&rem),
+                Fun1::as_ref(&norm),
+                zero.clone(),
+            )
+        })
+    })
+}
+
+fn non_decreasing_norm_law<'a, S, T, M, N>(
+    non_zero_vars: Vars<'a, S, 2>,
+    (mul_id, mul): (&str, &Rc<Fun2<'a, M>>),
+    (norm_id, norm): (&str, &Rc<Fun1<'a, N>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    T: Debug + PartialOrd,
+    M: Fn(S, S) -> S + 'a,
+    N: Fn(S) -> T + 'a,
+{
+    let id = format!("{}_is_non_decreasing_regarding_{}", norm_id, mul_id);
+    law::build(id, |id| {
+        let [a, b] = law::var_names(&non_zero_vars);
+        let name = non_decreasing_norm_name(norm.name, mul.name);
+        let statement = law::le(norm.label(a), norm.label(mul.label(a, b)));
+        let mul = Rc::clone(mul);
+        let norm = Rc::clone(norm);
+
+        Law::new(id, name, statement, 2, move || {
+            non_decreasing_norm(
+                non_zero_vars.clone(),
+                Fun2::as_ref(&mul),
+                Fun1::as_ref(&norm),
+            )
+        })
+    })
+}
+
+fn greatest_common_divisor_law<'a, S, G, D>(
+    vars: Vars<'a, S, 3>,
+    (gcd_id, gcd): (&str, &Rc<Fun2<'a, G>>),
+    (_, divides): (&str, &Rc<Fun2<'a, D>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    G: Fn(S, S) -> S + 'a,
+    D: Fn(S, S) -> bool + 'a,
+{
+    law::build(
+        format!("{}_returns_greatest_common_divisor", gcd_id),
+        |id| {
+            let [a, b, d] = law::var_names(&vars);
+            let name = greatest_common_divisor_name(gcd.name, divides.name);
+            let statement = format!(
+                "{}, {}",
+                law::and(
+                    divides.label(gcd.label(a, b), a),
+                    divides.label(gcd.label(a, b), b),
+                ),
+                law::implies(
+                    law::and(divides.label(d, a), divides.label(d, b)),
+                    divides.label(d, gcd.label(a, b)),
+                ),
+            );
+            let gcd = Rc::clone(gcd);
+            let divides = Rc::clone(divides);
+
+            Law::new(id, name, statement, 3, move || {
+                greatest_common_divisor(vars.clone(), Fun2::as_ref(&gcd), Fun2::as_ref(&divides))
+            })
+        },
+    )
+}
+
+fn scalar_multiplication_law<'a, V, K, A, B, M, P>(
+    vars: Vars<'a, V, 2>,
+    scalar_vars: Vars<'a, K, 2>,
+    (_, add): (&str, &Rc<Fun2<'a, A>>),
+    (_, scalar_add): (&str, &Rc<Fun2<'a, B>>),
+    (_, scalar_mul): (&str, &Rc<Fun2<'a, M>>),
+    (_, scalar_one): (&str, Elem<'a, K>),
+    (smul_id, smul): (&str, &Rc<Fun2<'a, P>>),
+) -> Option<Law<'a>>
+where
+    V: Debug + Clone + PartialEq + 'a,
+    K: Debug + Clone + 'a,
+    A: Fn(V, V) -> V + 'a,
+    B: Fn(K, K) -> K + 'a,
+    M: Fn(K, K) -> K + 'a,
+    P: Fn(K, V) -> V + 'a,
+{
+    law::build(format!("{}_is_scalar_multiplication", smul_id), |id| {
+        let [u, v] = law::var_names(&vars);
+        let [k, l] = law::var_names(&scalar_vars);
+        let name = scalar_multiplication_name(
+            smul.name,
+            add.name,
+            scalar_add.name,
+            scalar_mul.name,
+            scalar_one.name,
+        );
+        let statement = [
+            law::eq(
+                smul.label(k, add.label(u, v)),
+                add.label(smul.label(k, u), smul.label(k, v)),
+            ),
+            law::eq(
+                smul.label(scalar_add.label(k, l), u),
+                add.label(smul.label(k, u), smul.label(l, u)),
+            ),
+            law::eq(
+                smul.label(scalar_mul.label(k, l), u),
+                smul.label(k, smul.label(l, u)),
+            ),
+            law::eq(smul.label(scalar_one.name, u), u),
+        ]
+        .join(", ");
+        let add = Rc::clone(add);
+        let scalar_add = Rc::clone(scalar_add);
+        let scalar_mul = Rc::clone(scalar_mul);
+        let smul = Rc::clone(smul);
+
+        Law::new(id, name, statement, 4, move || {
+            scalar_multiplication(
+                vars.clone(),
+                scalar_vars.clone(),
+                Fun2::as_ref(&add),
+                Fun2::as_ref(&scalar_add),
+                Fun2::as_ref(&scalar_mul),
+                scalar_one.clone(),
+                Fun2::as_ref(&smul),
+            )
+        })
+    })
+}
+
+fn dot_symmetric_law<'a, V, K, D>(
+    vars: Vars<'a, V, 2>,
+    (dot_id, dot): (&str, &Rc<Fun2<'a, D>>),
+    tolerance: K,
+) -> Option<Law<'a>>
+where
+    V: Debug + Clone + 'a,
+    K: ApproxFloat + 'a,
+    D: Fn(V, V) -> K + 'a,
+{
+    law::build(format!("{}_is_symmetric", dot_id), |id| {
+        let [u, v] = law::var_names(&vars);
+        let name = dot_symmetric_name(dot.name);
+        let statement = law::approx_eq(dot.label(u, v), dot.label(v, u));
+        let dot = Rc::clone(dot);
+
+        Law::new(id, name, statement, 2, move || {
+            dot_symmetric(vars.clone(), Fun2::as_ref(&dot), tolerance)
+        })
+    })
+}
+
+fn dot_linear_law<'a, V, K, A, M, D>(
+    vars: Vars<'a, V, 3>,
+    scalar_vars: Vars<'a, K, 1>,
+    (_, add): (&str, &Rc<Fun2<'a, A>>),
+    (_, smul): (&str, &Rc<Fun2<'a, M>>),
+    (dot_id, dot): (&str, &Rc<Fun2<'a, D>>),
+    tolerance: K,
+) -> Option<Law<'a>>
+where
+    V: Debug + Clone + 'a,
+    K: ApproxFloat + 'a,
+    A: Fn(V, V) -> V + 'a,
+    M: Fn(K, V) -> V + 'a,
+    D: Fn(V, V) -> K + 'a,
+{
+    law::build(format!("{}_is_linear", dot_id), |id| {
+        let [u, v, w] = law::var_names(&vars);
+        let [k] = law::var_names(&scalar_vars);
+        let name = dot_linear_name(dot.name);
+        let statement = law::approx_eq(
+            dot.label(add.label(smul.label(k, u), v), w),
+            law::infix("+", law::infix("*", k, dot.label(u, w)), dot.label(v, w)),
+        );
+        let add = Rc::clone(add);
+        let smul = Rc::clone(smul);
+        let dot = Rc::clone(dot);
+
+        Law::new(id, name, statement, 4, move || {
+            dot_linear(
+                vars.clone(),
+                scalar_vars,
+                Fun2::as_ref(&add),
+                Fun2::as_ref(&smul),
+                Fun2::as_ref(&dot),
+                tolerance,
+            )
+        })
+    })
+}
+
+fn dot_positive_definite_law<'a, V, K, D>(
+    vars: Vars<'a, V, 1>,
+    (dot_id, dot): (&str, &Rc<Fun2<'a, D>>),
+    (_, zero): (&str, Elem<'a, V>),
+    tolerance: K,
+) -> Option<Law<'a>>
+where
+    V: Debug + Clone + PartialEq + 'a,
+    K: ApproxFloat + 'a,
+    D: Fn(V, V) -> K + 'a,
+{
+    law::build(format!("{}_is_positive_definite", dot_id), |id| {
+        let [u] = law::var_names(&vars);
+        let zero_scalar = zero_scalar::<K>().name;
+        let name = dot_positive_definite_name(dot.name);
+        let statement = format!(
+            "{}, {}",
+            law::approx_le(zero_scalar, dot.label(u, u)),
+            law::iff(law::eq(dot.label(u, u), zero_scalar), law::eq(u, zero.name),),
+        );
+        let dot = Rc::clone(dot);
+
+        Law::new(id, name, statement, 1, move || {
+            dot_positive_definite(vars.clone(), Fun2::as_ref(&dot), zero.clone(), tolerance)
+        })
+    })
+}
+
+fn cauchy_schwarz_law<'a, V, K, D>(
+    vars: Vars<'a, V, 2>,
+    (dot_id, dot): (&str, &Rc<Fun2<'a, D>>),
+    tolerance: K,
+) -> Option<Law<'a>>
+where
+    V: Debug + Clone + 'a,
+    K: ApproxFloat + 'a,
+    D: Fn(V, V) -> K + 'a,
+{
+    law::build(format!("{}_fulfills_cauchy_schwarz", dot_id), |id| {
+        let [u, v] = law::var_names(&vars);
+        let abs = abs_fun::<K>();
+        let sqrt = sqrt_fun::<K>();
+        let name = cauchy_schwarz_name(dot.name);
+        let statement = law::approx_le(
+            abs.label(dot.label(u, v)),
+            law::infix(
+                "*",
+                sqrt.label(dot.label(u, u)),
+                sqrt.label(dot.label(v, v)),
+            ),
+        );
+        let dot = Rc::clone(dot);
+
+        Law::new(id, name, statement, 2, move || {
+            cauchy_schwarz(vars.clone(), Fun2::as_ref(&dot), tolerance)
+        })
+    })
+}
+
+fn trivial_action_law<'a, G, X, A>(
+    target_vars: Vars<'a, X, 1>,
+    (e_id, e): (&str, Elem<'a, G>),
+    (act_id, act): (&str, &Rc<Fun2<'a, A>>),
+) -> Option<Law<'a>>
+where
+    G: Debug + Clone + 'a,
+    X: Debug + Clone + PartialEq + 'a,
+    A: Fn(G, X) -> X + 'a,
+{
+    law::build(format!("{}_is_trivial_with_{}", act_id, e_id), |id| {
+        let [x] = law::var_names(&target_vars);
+        let name = trivial_action_name(act.name, e.name);
+        let statement = law::eq(act.label(e.name, x), x);
+        let act = Rc::clone(act);
+
+        Law::new(id, name, statement, 1, move || {
+            trivial_action(target_vars.clone(), e.clone(), Fun2::as_ref(&act))
+        })
+    })
+}
+
+fn compatible_action_law<'a, G, X, O, A>(
+    vars: Vars<'a, G, 2>,
+    target_vars: Vars<'a, X, 1>,
+    (op_id, op): (&str, &Rc<Fun2<'a, O>>),
+    (act_id, act): (&str, &Rc<Fun2<'a, A>>),
+) -> Option<Law<'a>>
+where
+    G: Debug + Clone + 'a,
+    X: Debug + Clone + PartialEq + 'a,
+    O: Fn(G, G) -> G + 'a,
+    A: Fn(G, X) -> X + 'a,
+{
+    law::build(format!("{}_is_compatible_with_{}", act_id, op_id), |id| {
+        let [g, h] = law::var_names(&vars);
+        let [x] = law::var_names(&target_vars);
+        let name = compatible_action_name(act.name, op.name);
+        let statement = law::eq(act.label(op.label(g, h), x), act.label(g, act.label(h, x)));
+        let op = Rc::clone(op);
+        let act = Rc::clone(act);
+
+        Law::new(id, name, statement, 3, move || {
+            compatible_action(
+                vars.clone(),
+                target_vars.clone(),
+                Fun2::as_ref(&op),
+                Fun2::as_ref(&act),
+            )
+        })
+    })
+}
+
+fn undoing_action_law<'a, G, X, I, A>(
+    vars: Vars<'a, G, 1>,
+    target_vars: Vars<'a, X, 1>,
+    (inv_id, inv): (&str, &Rc<Fun1<'a, I>>),
+    (act_id, act): (&str, &Rc<Fun2<'a, A>>),
+) -> Option<Law<'a>>
+where
+    G: Debug + Clone + 'a,
+    X: Debug + Clone + PartialEq + 'a,
+    I: Fn(G) -> G + 'a,
+    A: Fn(G, X) -> X + 'a,
+{
+    law::build(format!("{}_undoes_{}", inv_id, act_id), |id| {
+        let [g] = law::var_names(&vars);
+        let [x] = law::var_names(&target_vars);
+        let name = undoing_action_name(act.name, inv.name);
+        let statement = law::eq(act.label(inv.label(g), act.label(g, x)), x);
+        let inv = Rc::clone(inv);
+        let act = Rc::clone(act);
+
+        Law::new(id, name, statement, 2, move || {
+            undoing_action(
+                vars.clone(),
+                target_vars.clone(),
+                Fun1::as_ref(&inv),
+                Fun2::as_ref(&act),
+            )
+        })
+    })
+}
+
+fn zero_scalar<'a, K: ApproxFloat>() -> Elem<'a, K> {
+    Elem::new("0", K::ZERO)
+}
+
+fn abs_fun<K: ApproxFloat>() -> Fun1<'static, impl Fn(K) -> K> {
+    Fun1::new("abs", K::abs)
+}
+
+fn sqrt_fun<K: ApproxFloat>() -> Fun1<'static, impl Fn(K) -> K> {
+    Fun1::new("√", K::sqrt)
+}
+
+fn euclidean_division_name(div: &str, rem: &str, norm: &str) -> String {
+    format!(
+        "Are `{}` and `{}` a Euclidean division regarding to `{}`?",
+        div, rem, norm
+    )
+}
+
+fn non_decreasing_norm_name(norm: &str, mul: &str) -> String {
+    format!("Is `{}` non-decreasing regarding to `{}`?", norm, mul)
+}
+
+fn greatest_common_divisor_name(gcd: &str, divides: &str) -> String {
+    format!(
+        "Does `{}` return the greatest common divisor regarding to `{}`?",
+        gcd, divides
+    )
+}
+
+fn scalar_multiplication_name(
+    smul: &str,
+    add: &str,
+    scalar_add: &str,
+    scalar_mul: &str,
+    scalar_one: &str,
+) -> String {
+    format!(
+        "Is `{}` a scalar multiplication regarding to `{}`, `{}`, `{}` and `{}`?",
+        smul, add, scalar_add, scalar_mul, scalar_one
+    )
+}
+
+fn dot_symmetric_name(dot: &str) -> String {
+    format!("Is `{}` symmetric?", dot)
+}
+
+fn dot_linear_name(dot: &str) -> String {
+    format!("Is `{}` linear in the first argument?", dot)
+}
+
+fn dot_positive_definite_name(dot: &str) -> String {
+    format!("Is `{}` positive-definite?", dot)
+}
+
+fn cauchy_schwarz_name(dot: &str) -> String {
+    format!("Does `{}` fulfill the Cauchy–Schwarz inequality?", dot)
+}
+
+fn trivial_action_name(act: &str, e: &str) -> String {
+    format!("Does `{}` act trivially with `{}`?", act, e)
+}
+
+fn compatible_action_name(act: &str, op: &str) -> String {
+    format!("Is `{}` compatible with `{}`?", act, op)
+}
+
+fn undoing_action_name(act: &str, inv: &str) -> String {
+    format!("Does `{}` undo `{}`?", inv, act)
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{check, check_laws, props, Elem, Fun1, Fun2, Set, Vars};
+
+    #[test]
+    fn semigroup_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("u64", dice::u64(..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let op = Fun2::infix("+", |x, y| x + y);
+            props::algebra::semigroup(vars, op);
+        })
+    }
+
+    #[test]
+    fn monoid_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("u64", dice::u64(..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let op = Fun2::infix("+", |x, y| x + y);
+            let e = Elem::new("zero", 0);
+            props::algebra::monoid(vars, op, e);
+        })
+    }
+
+    #[test]
+    fn group_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let op = Fun2::infix("+", |x, y| x + y);
+            let inv = Fun1::new("-", |x: i64| -x);
+            let e = Elem::new("zero", 0);
+            props::algebra::group(vars, op, inv, e);
+        })
+    }
+
+    #[test]
+    fn abelian_group_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let op = Fun2::infix("+", |x, y| x + y);
+            let inv = Fun1::new("-", |x: i64| -x);
+            let e = Elem::new("zero", 0);
+            props::algebra::abelian_group(vars, op, inv, e);
+        })
+    }
+
+    #[test]
+    fn ring_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::ring(vars, add, mul, neg, zero, one);
+        })
+    }
+
+    #[test]
+    fn commutative_ring_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::commutative_ring(vars, add, mul, neg, zero, one);
+        })
+    }
+
+    #[test]
+    fn field_example() {
+        // Are there any fields in libstd?
+    }
+
+    #[test]
+    fn integral_domain_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let non_zero_set = Set::new("i64 ∩ [1,1000]", dice::i64(1..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let non_zero_vars = fate.roll(non_zero_set.vars(["a", "b"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::integral_domain(vars, non_zero_vars, add, mul, neg, zero, one);
+        })
+    }
+
+    #[test]
+    fn euclidean_domain_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let non_zero_set = Set::new("i64 ∩ [-1000,-1]", dice::i64(-1000..=-1));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let non_zero_vars = fate.roll(non_zero_set.vars(["a", "b"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let div = Fun2::infix("/", |x, y| x / y);
+            let rem = Fun2::infix("%", |x, y| x % y);
+            let norm = Fun1::new("abs", |x: i64| x.abs());
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::euclidean_domain(
+                vars,
+                non_zero_vars,
+                add,
+                mul,
+                neg,
+                div,
+                rem,
+                norm,
+                zero,
+                one,
+            );
+        })
+    }
+
+    #[test]
+    fn gcd_domain_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let non_zero_set = Set::new("i64 ∩ [1,1000]", dice::i64(1..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let non_zero_vars = fate.roll(non_zero_set.vars(["a", "b"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let gcd = Fun2::new("gcd", |mut x: i64, mut y: i64| {
+                while y != 0 {
+                    (x, y) = (y, x % y);
+                }
+                x.abs()
+            });
+            let divides = Fun2::infix(
+                "|",
+                |x: i64, y: i64| {
+                    if x == 0 {
+                        y == 0
+                    } else {
+                        y % x == 0
+                    }
+                },
+            );
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::gcd_domain(vars, non_zero_vars, add, mul, neg, gcd, divides, zero, one);
+        })
+    }
+
+    #[test]
+    fn ordered_group_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let op = Fun2::infix("+", |x, y| x + y);
+            let inv = Fun1::new("-", |x: i64| -x);
+            let le = Fun2::infix("<=", |x, y| x <= y);
+            let e = Elem::new("zero", 0);
+            props::algebra::ordered_group(vars, op, inv, le, e);
+        })
+    }
+
+    #[test]
+    fn ordered_ring_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let le = Fun2::infix("<=", |x, y| x <= y);
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::ordered_ring(vars, add, mul, neg, le, zero, one);
+        })
+    }
+
+    #[test]
+    fn ordered_field_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("ℚ", ratio_die(-100..=100));
+            let non_zero_set = Set::new("ℚ ∩ (0,100]", ratio_die(1..=100));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let non_zero_vars = fate.roll(non_zero_set.vars(["a", "b"]));
+            let add = Fun2::infix("+", Ratio::add);
+            let mul = Fun2::infix("*", Ratio::mul);
+            let neg = Fun1::new("-", Ratio::neg);
+            let inv = Fun1::new("inv", Ratio::inv);
+            let le = Fun2::infix("<=", |x: Ratio, y: Ratio| x <= y);
+            let zero = Elem::new("zero", Ratio::new(0, 1));
+            let one = Elem::new("one", Ratio::new(1, 1));
+            props::algebra::ordered_field(vars, non_zero_vars, add, mul, neg, inv, le, zero, one);
+        })
+    }
+
+    #[test]
+    fn ordered_field_detects_non_negative_products() {
+        let vars = Vars::new(
+            "ℚ",
+            [
+                Elem::new("x", Ratio::new(-1, 2)),
+                Elem::new("y", Ratio::new(-2, 3)),
+                Elem::new("z", Ratio::new(3, 4)),
+            ],
+        );
+        let non_zero_vars = Vars::new(
+            "ℚ",
+            [
+                Elem::new("a", Ratio::new(1, 2)),
+                Elem::new("b", Ratio::new(-5, 3)),
+            ],
+        );
+        let add = Fun2::infix("+", Ratio::add);
+        let mul = Fun2::infix("*", Ratio::mul);
+        let neg = Fun1::new("-", Ratio::neg);
+        let inv = Fun1::new("inv", Ratio::inv);
+        let ge = Fun2::infix(">=", |x: Ratio, y: Ratio| x >= y);
+        let zero = Elem::new("zero", Ratio::new(0, 1));
+        let one = Elem::new("one", Ratio::new(1, 1));
+
+        let failure = check(|| {
+            props::algebra::ordered_field(vars, non_zero_vars, add, mul, neg, inv, ge, zero, one)
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            failure.section.unwrap(),
+            "Is `*` closed for elements not less than `zero` regarding to `>=`?"
+        );
+    }
+
+    #[test]
+    fn module_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64² ∩ [-1000,1000]²", dice::array(dice::i64(-1000..=1000)));
+            let scalar_set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["u", "v"]));
+            let scalar_vars = fate.roll(scalar_set.vars(["k", "l", "m"]));
+            let add = Fun2::infix("+", |[u1, u2]: [i64; 2], [v1, v2]: [i64; 2]| {
+                [u1 + v1, u2 + v2]
+            });
+            let neg = Fun1::new("-", |[u1, u2]: [i64; 2]| [-u1, -u2]);
+            let zero = Elem::new("zero", [0, 0]);
+            let scalar_add = Fun2::infix("+", |k, l| k + l);
+            let scalar_mul = Fun2::infix("*", |k, l| k * l);
+            let scalar_neg = Fun1::new("-", |k: i64| -k);
+            let scalar_zero = Elem::new("zero", 0);
+            let scalar_one = Elem::new("one", 1);
+            let smul = Fun2::infix("·", |k: i64, [u1, u2]: [i64; 2]| [k * u1, k * u2]);
+            props::algebra::module(
+                vars,
+                scalar_vars,
+                add,
+                neg,
+                zero,
+                scalar_add,
+                scalar_mul,
+                scalar_neg,
+                scalar_zero,
+                scalar_one,
+                smul,
+            );
+        })
+    }
+
+    #[test]
+    fn module_detects_non_ring_scalars() {
+        let vars = Vars::new("i64²", [Elem::new("u", [1, 2]), Elem::new("v", [3, 4])]);
+        let scalar_vars = Vars::new(
+            "i64",
+            [Elem::new("k", 1), Elem::new("l", 2), Elem::new("m", 3)],
+        );
+        let add = Fun2::infix("+", |[u1, u2]: [i64; 2], [v1, v2]: [i64; 2]| {
+            [u1 + v1, u2 + v2]
+        });
+        let neg = Fun1::new("-", |[u1, u2]: [i64; 2]| [-u1, -u2]);
+        let zero = Elem::new("zero", [0, 0]);
+        let scalar_add = Fun2::infix("+", |k, l| k + l);
+        let scalar_mul = Fun2::infix("*", |k, l| k - l);
+        let scalar_neg = Fun1::new("-", |k: i64| -k);
+        let scalar_zero = Elem::new("zero", 0);
+        let scalar_one = Elem::new("one", 1);
+        let smul = Fun2::infix("·", |k: i64, [u1, u2]: [i64; 2]| [k * u1, k * u2]);
+
+        let failure = check(|| {
+            props::algebra::module(
+                vars,
+                scalar_vars,
+                add,
+                neg,
+                zero,
+                scalar_add,
+                scalar_mul,
+                scalar_neg,
+                scalar_zero,
+                scalar_one,
+                smul,
+            )
+        })
+        .unwrap_err();
+
+        assert_eq!(failure.section.unwrap(), "Is `*` associative?");
+    }
+
+    #[test]
+    fn vector_space_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("u8 as GF(2)⁸", dice::u8(..));
+            let scalar_set = Set::new("bool as GF(2)", dice::bool());
+            let non_zero_scalar_set = Set::new("{true}", dice::just(true));
+            let vars = fate.roll(set.vars(["u", "v"]));
+            let scalar_vars = fate.roll(scalar_set.vars(["k", "l", "m"]));
+            let non_zero_scalar_vars = fate.roll(non_zero_scalar_set.vars(["p", "q"]));
+            let add = Fun2::infix("^", |u, v| u ^ v);
+            let neg = Fun1::new("id", |u: u8| u);
+            let zero = Elem::new("zero", 0);
+            let scalar_add = Fun2::infix("^", |k, l| k ^ l);
+            let scalar_mul = Fun2::infix("&", |k, l| k & l);
+            let scalar_neg = Fun1::new("id", |k: bool| k);
+            let scalar_inv = Fun1::new("id", |k: bool| k);
+            let scalar_zero = Elem::new("false", false);
+            let scalar_one = Elem::new("true", true);
+            let smul = Fun2::infix("·", |k: bool, u: u8| if k { u } else { 0 });
+            props::algebra::vector_space(
+                vars,
+                scalar_vars,
+                non_zero_scalar_vars,
+                add,
+                neg,
+                zero,
+                scalar_add,
+                scalar_mul,
+                scalar_neg,
+                scalar_inv,
+                scalar_zero,
+                scalar_one,
+                smul,
+            );
+        })
+    }
+
+    #[test]
+    fn vector_space_detects_non_field_scalars() {
+        let vars = Vars::new("i64²", [Elem::new("u", [1, 2]), Elem::new("v", [3, 4])]);
+        let scalar_vars = Vars::new(
+            "i64",
+            [Elem::new("k", 1), Elem::new("l", 2), Elem::new("m", 3)],
+        );
+        let non_zero_scalar_vars = Vars::new("i64", [Elem::new("p", 2), Elem::new("q", 3)]);
+        let add = Fun2::infix("+", |[u1, u2]: [i64; 2], [v1, v2]: [i64; 2]| {
+            [u1 + v1, u2 + v2]
+        });
+        let neg = Fun1::new("-", |[u1, u2]: [i64; 2]| [-u1, -u2]);
+        let zero = Elem::new("zero", [0, 0]);
+        let scalar_add = Fun2::infix("+", |k, l| k + l);
+        let scalar_mul = Fun2::infix("*", |k, l| k * l);
+        let scalar_neg = Fun1::new("-", |k: i64| -k);
+        let scalar_inv = Fun1::new("inv", |k: i64| 1 / k);
+        let scalar_zero = Elem::new("zero", 0);
+        let scalar_one = Elem::new("one", 1);
+        let smul = Fun2::infix("·", |k: i64, [u1, u2]: [i64; 2]| [k * u1, k * u2]);
+
+        let failure = check(|| {
+            props::algebra::vector_space(
+                vars,
+                scalar_vars,
+                non_zero_scalar_vars,
+                add,
+                neg,
+                zero,
+                scalar_add,
+                scalar_mul,
+                scalar_neg,
+                scalar_inv,
+                scalar_zero,
+                scalar_one,
+                smul,
+            )
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            failure.section.unwrap(),
+            "Does `inv` return left inverse element regarding to `*`?"
+        );
+    }
+
+    #[test]
+    fn inner_product_space_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new(
+                "f64² ∩ [-100,100]² ∩ 0.001ℤ²",
+                dice::array(dice::i32(-100_000..=100_000).map(|x| x as f64 / 1000.0)),
+            );
+            let scalar_set = Set::new(
+                "f64 ∩ [-100,100] ∩ 0.001ℤ",
+                dice::i32(-100_000..=100_000).map(|x| x as f64 / 1000.0),
+            );
+            let vars = fate.roll(set.vars(["u", "v", "w"]));
+            let scalar_vars = fate.roll(scalar_set.vars(["k"]));
+            let add = Fun2::infix("+", |[u1, u2]: [f64; 2], [v1, v2]: [f64; 2]| {
+                [u1 + v1, u2 + v2]
+            });
+            let smul = Fun2::infix("·", |k: f64, [u1, u2]: [f64; 2]| [k * u1, k * u2]);
+            let dot = Fun2::new("dot", |[u1, u2]: [f64; 2], [v1, v2]: [f64; 2]| {
+                u1 * v1 + u2 * v2
+            });
+            let zero = Elem::new("zero", [0.0, 0.0]);
+            props::algebra::inner_product_space(vars, scalar_vars, add, smul, dot, zero, 1e-9);
+        })
+    }
+
+    #[test]
+    fn normed_space_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new(
+                "f64² ∩ [-100,100]² ∩ 0.001ℤ²",
+                dice::array(dice::i32(-100_000..=100_000).map(|x| x as f64 / 1000.0)),
+            );
+            let scalar_set = Set::new(
+                "f64 ∩ [-100,100] ∩ 0.001ℤ",
+                dice::i32(-100_000..=100_000).map(|x| x as f64 / 1000.0),
+            );
+            let vars = fate.roll(set.vars(["u", "v"]));
+            let scalar_vars = fate.roll(scalar_set.vars(["k"]));
+            let add = Fun2::infix("+", |[u1, u2]: [f64; 2], [v1, v2]: [f64; 2]| {
+                [u1 + v1, u2 + v2]
+            });
+            let smul = Fun2::infix("·", |k: f64, [u1, u2]: [f64; 2]| [k * u1, k * u2]);
+            let norm = Fun1::new("norm", |[u1, u2]: [f64; 2]| u1.hypot(u2));
+            let zero = Elem::new("zero", [0.0, 0.0]);
+            props::algebra::normed_space(vars, scalar_vars, add, smul, norm, zero, 1e-9);
+        })
+    }
+
+    #[test]
+    fn normed_space_of_f32_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new(
+                "f32² ∩ [-100,100]² ∩ 0.001ℤ²",
+                dice::array(dice::i32(-100_000..=100_000).map(|x| x as f32 / 1000.0)),
+            );
+            let scalar_set = Set::new(
+                "f32 ∩ [-100,100] ∩ 0.001ℤ",
+                dice::i32(-100_000..=100_000).map(|x| x as f32 / 1000.0),
+            );
+            let vars = fate.roll(set.vars(["u", "v"]));
+            let scalar_vars = fate.roll(scalar_set.vars(["k"]));
+            let add = Fun2::infix("+", |[u1, u2]: [f32; 2], [v1, v2]: [f32; 2]| {
+                [u1 + v1, u2 + v2]
+            });
+            let smul = Fun2::infix("·", |k: f32, [u1, u2]: [f32; 2]| [k * u1, k * u2]);
+            let norm = Fun1::new("norm", |[u1, u2]: [f32; 2]| u1.hypot(u2));
+            let zero = Elem::new("zero", [0.0, 0.0]);
+            props::algebra::normed_space(vars, scalar_vars, add, smul, norm, zero, 1e-5);
+        })
+    }
+
+    #[test]
+    fn normed_space_detects_out_of_tolerance() {
+        let vars = Vars::new(
+            "f64²",
+            [Elem::new("u", [3.0, 4.0]), Elem::new("v", [0.0, 0.0])],
+        );
+        let scalar_vars = Vars::new("f64", [Elem::new("k", 2.0)]);
+        let add = Fun2::infix("+", |[u1, u2]: [f64; 2], [v1, v2]: [f64; 2]| {
+            [u1 + v1, u2 + v2]
+        });
+        let smul = Fun2::infix("·", |k: f64, [u1, u2]: [f64; 2]| [k * u1, k * u2]);
+        // The squared norm is not homogeneous
+        let norm = Fun1::new("sq_norm", |[u1, u2]: [f64; 2]| u1 * u1 + u2 * u2);
+        let zero = Elem::new("zero", [0.0, 0.0]);
+
+        let failure =
+            check(|| props::algebra::normed_space(vars, scalar_vars, add, smul, norm, zero, 1e-6))
+                .unwrap_err();
+
+        assert_eq!(
+            failure.assertion,
+            "(sq_norm((k · u)) ≈ (abs(k) * sq_norm(u)))"
+        );
+    }
+
+    #[test]
+    fn monoid_action_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("String", dice::string(dice::char(), ..));
+            let target_set = Set::new("String", dice::string(dice::char(), ..));
+            let vars = fate.roll(set.vars(["g", "h"]));
+            let target_vars = fate.roll(target_set.vars(["x"]));
+            let op = Fun2::infix("+", |g: String, h: String| g + &h);
+            let e = Elem::new("empty", String::new());
+            let act = Fun2::new("prepend", |g: String, x: String| g + &x);
+            props::algebra::monoid_action(vars, target_vars, op, e, act);
+        })
+    }
+
+    #[test]
+    fn group_action_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-100,100]", dice::i64(-100..=100));
+            let target_set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..));
+            let vars = fate.roll(set.vars(["g", "h"]));
+            let target_vars = fate.roll(target_set.vars(["x"]));
+            let op = Fun2::infix("+", |g, h| g + h);
+            let inv = Fun1::new("-", |g: i64| -g);
+            let e = Elem::new("zero", 0);
+            let act = Fun2::new("rotate", |g: i64, mut x: Vec<u8>| {
+                if !x.is_empty() {
+                    let mid = g.rem_euclid(x.len() as i64) as usize;
+                    x.rotate_left(mid);
+                }
+                x
+            });
+            props::algebra::group_action(vars, target_vars, op, inv, e, act);
+        })
+    }
+
+    #[test]
+    fn fold_consistent_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new(
+                "Vec<String>",
+                dice::vec(dice::string(dice::char(), ..5), ..),
+            );
+            let vars = fate.roll(set.vars(["xs"]));
+            let op = Fun2::infix("+", |x: String, y: String| x + &y);
+            let e = Elem::new("empty", String::new());
+            let concat_all = Fun1::new("concat", |xs: Vec<String>| xs.concat());
+            props::algebra::fold_consistent(vars, op, e, concat_all);
+        })
+    }
+
+    #[test]
+    fn split_invariant_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<u64>", dice::vec(dice::u64(..=1000), ..));
+            let split_set = Set::new("usize", dice::usize(..));
+            let vars = fate.roll(set.vars(["zs"]));
+            let split_vars = fate.roll(split_set.vars(["i"]));
+            let f = Fun1::new("sum", |xs: Vec<u64>| xs.into_iter().sum::<u64>());
+            let op = Fun2::infix("+", |x, y| x + y);
+            props::algebra::split_invariant(vars, split_vars, f, op);
+        })
+    }
+
+    #[test]
+    fn abelian_group_of_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let zero = Elem::new("zero", 0);
+            props::algebra::abelian_group_of(vars, zero);
+        })
+    }
+
+    #[test]
+    fn ring_of_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::ring_of(vars, zero, one);
+        })
+    }
+
+    #[test]
+    fn commutative_ring_of_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            props::algebra::commutative_ring_of(vars, zero, one);
+        })
+    }
+
+    #[test]
+    fn ring_laws_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let add = Fun2::infix("+", |x, y| x + y);
+            let mul = Fun2::infix("*", |x, y| x * y);
+            let neg = Fun1::new("-", |x: i64| -x);
+            let zero = Elem::new("zero", 0);
+            let one = Elem::new("one", 1);
+            let laws = props::algebra::ring_laws(vars, add, mul, neg, zero, one);
+
+            let names = laws.iter().map(|law| law.name.as_str()).collect::<Vec<_>>();
+            assert_eq!(
+                names,
+                [
+                    "Is `+` associative?",
+                    "Is `zero` identity element of `+`?",
+                    "Does `-` return inverse element regarding to `+`?",
+                    "Is `+` commutative?",
+                    "Is `*` associative?",
+                    "Is `one` identity element of `*`?",
+                    "Is `*` distributive over `+`?",
+                ]
+            );
+            assert_eq!(laws[0].statement, "(((x + y) + z) == (x + (y + z)))");
+            assert_eq!(
+                laws[2].statement,
+                "((y + (-(x) + x)) == y), (((x + -(x)) + y) == y)"
+            );
+
+            for law in laws {
+                law.assert();
+            }
+        })
+    }
+
+    #[test]
+    fn vector_space_laws_reports_every_failure() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-100,100]", dice::i64(-100..=100));
+            let scalar_set = Set::new("i64 ∩ [2,100]", dice::i64(2..=100));
+            let vars = fate.roll(set.vars(["u", "v"]));
+            let scalar_vars = fate.roll(scalar_set.vars(["k", "l", "m"]));
+            let non_zero_scalar_set = Set::new("i64 ∩ [2,100]", dice::i64(2..=100));
+            let non_zero_scalar_vars = fate.roll(non_zero_scalar_set.vars(["p", "q"]));
+            let add = Fun2::infix("+", |u: i64, v: i64| u + v);
+            let neg = Fun1::new("-", |u: i64| -u);
+            let zero = Elem::new("zero", 0);
+            let scalar_add = Fun2::infix("+", |k: i64, l: i64| k + l);
+            let scalar_mul = Fun2::infix("*", |k: i64, l: i64| k * l);
+            let scalar_neg = Fun1::new("-", |k: i64| -k);
+            let scalar_inv = Fun1::new("inv", |k: i64| 1 / k);
+            let scalar_zero = Elem::new("0", 0);
+            let scalar_one = Elem::new("1", 1);
+            let smul = Fun2::infix("·", |k: i64, u: i64| k + u);
+            let laws = props::algebra::vector_space_laws(
+                vars,
+                scalar_vars,
+                non_zero_scalar_vars,
+                add,
+                neg,
+                zero,
+                scalar_add,
+                scalar_mul,
+                scalar_neg,
+                scalar_inv,
+                scalar_zero,
+                scalar_one,
+                smul,
+            );
+
+            let failures = check_laws(&laws).unwrap_err();
+            let sections = failures
+                .iter()
+                .map(|failure| failure.section.as_deref().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                sections,
+                [
+                    "Does `inv` return left inverse element regarding to `*`?",
+                    "Is `·` a scalar multiplication regarding to `+`, `+`, `*` and `1`?",
+                ]
+            );
+        })
+    }
+
+    /// A rational number in lowest terms with a positive denominator.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Ratio {
+        num: i64,
+        den: i64,
+    }
+
+    impl Ratio {
+        fn new(num: i64, den: i64) -> Self {
+            let (mut a, mut b) = (num.abs(), den.abs());
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            let sign = den.signum();
+            Ratio {
+                num: sign * num / a,
+                den: sign * den / a,
+            }
+        }
+
+        fn add(self, other: Self) -> Self {
+            Ratio::new(
+                self.num * other.den + other.num * self.den,
+                self.den * other.den,
+            )
+        }
+
+        fn mul(self, other: Self) -> Self {
+            Ratio::new(self.num * other.num, self.den * other.den)
+        }
+
+        fn neg(self) -> Self {
+            Ratio::new(-self.num, self.den)
+        }
+
+        fn inv(self) -> Self {
+            Ratio::new(self.den, self.num)
+        }
+    }
+
+    impl PartialOrd for Ratio {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            (self.num * other.den).partial_cmp(&(other.num * self.den))
+        }
+    }
+
+    fn ratio_die(nums: std::ops::RangeInclusive<i64>) -> impl Die<Ratio> {
+        dice::zip()
+            .two(dice::i64(nums), dice::i64(1..=100))
+            .map(|(num, den)| Ratio::new(num, den))
     }
 }
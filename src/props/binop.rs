@@ -4,7 +4,9 @@
 
 use dicetest::hint_section;
 use std::fmt::Debug;
+use std::rc::Rc;
 
+use crate::law::{self, Law};
 use crate::{ops, props, Elem, Fun1, Fun2, Vars};
 
 /// Asserts that the binary operation `op` is [commutative].
@@ -32,7 +34,7 @@ where
     S: Debug + Clone + PartialEq,
     O: Fn(S, S) -> S,
 {
-    hint_section!("Is `{}` associative?", op.name);
+    hint_section!("{}", associative_name(op.name));
 
     let [a, b, c] = vars.eval();
 
@@ -107,7 +109,7 @@ where
     A: Fn(S, S) -> S,
     M: Fn(S, S) -> S,
 {
-    hint_section!("Is `{}` distributive over `{}`?", mul.name, add.name);
+    hint_section!("{}", distributive_name(add.name, mul.name));
 
     left_distributive(vars.clone(), add.as_ref(), mul.as_ref());
     right_distributive(vars, add, mul);
@@ -163,7 +165,7 @@ where
     S: Debug + Clone + PartialEq,
     O: Fn(S, S) -> S,
 {
-    hint_section!("Is `{}` identity element of `{}`?", e.name, op.name);
+    hint_section!("{}", identity_elem_name(op.name, e.name));
 
     left_identity_elem(vars.clone(), op.as_ref(), e.clone());
     right_identity_elem(vars, op, e);
@@ -241,16 +243,34 @@ where
     O: Fn(S, S) -> S,
     I: Fn(S) -> S,
 {
-    hint_section!(
-        "Does `{}` return inverse element regarding to `{}`?",
-        inv.name,
-        op.name
-    );
+    hint_section!("{}", inverse_elem_name(op.name, inv.name));
 
     left_inverse_elem(vars.clone(), op.as_ref(), inv.as_ref());
     right_inverse_elem(vars, op, inv);
 }
 
+/// Asserts that the binary operation `op` has no [zero divisors] regarding to the element
+/// `zero`.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `op(a, b) != zero`
+///
+/// Hence `vars.set` must not contain `zero`.
+///
+/// [zero divisors]: https://en.wikipedia.org/wiki/Zero_divisor
+pub fn no_zero_divisors<S, O>(vars: Vars<S, 2>, op: Fun2<O>, zero: Elem<S>)
+where
+    S: Debug + Clone + PartialEq,
+    O: FnOnce(S, S) -> S,
+{
+    hint_section!("{}", no_zero_divisors_name(op.name, zero.name));
+
+    let [a, b] = vars.eval();
+    let zero = zero.eval();
+
+    ops::assert(ops::ne(op.eval_once(a, b).as_ref(), zero.as_ref()));
+}
+
 /// Asserts that the binary operation `invop` is the left inverse of the binary operation `op`.
 ///
 /// For all `a`, `b` of `vars.set` it must hold:
@@ -332,6 +352,164 @@ where
     ));
 }
 
+pub(crate) fn no_zero_divisors_law<'a, S, O>(
+    vars: Vars<'a, S, 2>,
+    (op_id, op): (&str, &Rc<Fun2<'a, O>>),
+    (zero_id, zero): (&str, Elem<'a, S>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    law::build(
+        format!("{}_has_no_zero_divisors_of_{}", op_id, zero_id),
+        |id| {
+            let [a, b] = law::var_names(&vars);
+            let name = no_zero_divisors_name(op.name, zero.name);
+            let statement = law::ne(op.label(a, b), zero.name);
+            let op = Rc::clone(op);
+
+            Law::new(id, name, statement, 2, move || {
+                no_zero_divisors(vars.clone(), Fun2::as_ref(&op), zero.clone())
+            })
+        },
+    )
+}
+
+pub(crate) fn associative_law<'a, S, O>(
+    vars: Vars<'a, S, 3>,
+    (op_id, op): (&str, &Rc<Fun2<'a, O>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    law::build(format!("{}_is_associative", op_id), |id| {
+        let [a, b, c] = law::var_names(&vars);
+        let name = associative_name(op.name);
+        let statement = law::eq(op.label(op.label(a, b), c), op.label(a, op.label(b, c)));
+        let op = Rc::clone(op);
+
+        Law::new(id, name, statement, 3, move || {
+            associative(vars.clone(), Fun2::as_ref(&op))
+        })
+    })
+}
+
+pub(crate) fn distributive_law<'a, S, A, M>(
+    vars: Vars<'a, S, 3>,
+    (add_id, add): (&str, &Rc<Fun2<'a, A>>),
+    (mul_id, mul): (&str, &Rc<Fun2<'a, M>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    A: Fn(S, S) -> S + 'a,
+    M: Fn(S, S) -> S + 'a,
+{
+    law::build(
+        format!("{}_is_distributive_over_{}", mul_id, add_id),
+        |id| {
+            let [a, b, c] = law::var_names(&vars);
+            let name = distributive_name(add.name, mul.name);
+            let statement = format!(
+                "{}, {}",
+                law::eq(
+                    mul.label(a, add.label(b, c)),
+                    add.label(mul.label(a, b), mul.label(a, c)),
+                ),
+                law::eq(
+                    mul.label(add.label(a, b), c),
+                    add.label(mul.label(a, c), mul.label(b, c)),
+                ),
+            );
+            let add = Rc::clone(add);
+            let mul = Rc::clone(mul);
+
+            Law::new(id, name, statement, 3, move || {
+                distributive(vars.clone(), Fun2::as_ref(&add), Fun2::as_ref(&mul))
+            })
+        },
+    )
+}
+
+pub(crate) fn identity_elem_law<'a, S, O>(
+    vars: Vars<'a, S, 1>,
+    (op_id, op): (&str, &Rc<Fun2<'a, O>>),
+    (e_id, e): (&str, Elem<'a, S>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    law::build(format!("{}_is_identity_elem_of_{}", e_id, op_id), |id| {
+        let [a] = law::var_names(&vars);
+        let name = identity_elem_name(op.name, e.name);
+        let statement = format!(
+            "{}, {}",
+            law::eq(op.label(e.name, a), a),
+            law::eq(op.label(a, e.name), a),
+        );
+        let op = Rc::clone(op);
+
+        Law::new(id, name, statement, 1, move || {
+            identity_elem(vars.clone(), Fun2::as_ref(&op), e.clone())
+        })
+    })
+}
+
+pub(crate) fn inverse_elem_law<'a, S, O, I>(
+    vars: Vars<'a, S, 2>,
+    (op_id, op): (&str, &Rc<Fun2<'a, O>>),
+    (inv_id, inv): (&str, &Rc<Fun1<'a, I>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    O: Fn(S, S) -> S + 'a,
+    I: Fn(S) -> S + 'a,
+{
+    law::build(
+        format!("{}_returns_inverse_elem_of_{}", inv_id, op_id),
+        |id| {
+            let [a, b] = law::var_names(&vars);
+            let name = inverse_elem_name(op.name, inv.name);
+            let statement = format!(
+                "{}, {}",
+                law::eq(op.label(b, op.label(inv.label(a), a)), b),
+                law::eq(op.label(op.label(a, inv.label(a)), b), b),
+            );
+            let op = Rc::clone(op);
+            let inv = Rc::clone(inv);
+
+            Law::new(id, name, statement, 2, move || {
+                inverse_elem(vars.clone(), Fun2::as_ref(&op), Fun1::as_ref(&inv))
+            })
+        },
+    )
+}
+
+fn no_zero_divisors_name(op: &str, zero: &str) -> String {
+    format!("Has `{}` no zero divisors regarding to `{}`?", op, zero)
+}
+
+fn associative_name(op: &str) -> String {
+    format!("Is `{}` associative?", op)
+}
+
+fn distributive_name(add: &str, mul: &str) -> String {
+    format!("Is `{}` distributive over `{}`?", mul, add)
+}
+
+fn identity_elem_name(op: &str, e: &str) -> String {
+    format!("Is `{}` identity element of `{}`?", e, op)
+}
+
+fn inverse_elem_name(op: &str, inv: &str) -> String {
+    format!(
+        "Does `{}` return inverse element regarding to `{}`?",
+        inv, op
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use dicetest::prelude::*;
@@ -465,6 +643,17 @@ mod tests {
         })
     }
 
+    #[test]
+    fn no_zero_divisors_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [1,1000]", dice::i64(1..=1000));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let op = Fun2::infix("*", |x, y| x * y);
+            let zero = Elem::new("zero", 0);
+            props::binop::no_zero_divisors(vars, op, zero);
+        })
+    }
+
     #[test]
     fn left_inverse_example() {
         Dicetest::once().run(|mut fate| {
@@ -4,8 +4,10 @@
 
 use dicetest::hint_section;
 use std::fmt::Debug;
+use std::rc::Rc;
 
-use crate::{ops, Fun2, Vars};
+use crate::law::{self, Law};
+use crate::{ops, Elem, Fun2, Vars};
 
 /// Asserts that the binary relation `rel` is [reflexive].
 ///
@@ -18,7 +20,7 @@ where
     S: Debug + Clone,
     R: FnOnce(S, S) -> bool,
 {
-    hint_section!("Is `{}` reflexive?", rel.name);
+    hint_section!("{}", reflexive_name(rel.name));
 
     let [a] = vars.eval();
 
@@ -36,7 +38,7 @@ where
     S: Debug + Clone,
     R: Fn(S, S) -> bool,
 {
-    hint_section!("Is `{}` symmetric?", rel.name);
+    hint_section!("{}", symmetric_name(rel.name));
 
     let [a, b] = vars.eval();
 
@@ -75,7 +77,7 @@ where
     S: Debug + Clone + PartialEq,
     R: Fn(S, S) -> bool,
 {
-    hint_section!("Is `{}` antisymmetric?", rel.name);
+    hint_section!("{}", antisymmetric_name(rel.name));
 
     let [a, b] = vars.eval();
 
@@ -99,7 +101,7 @@ where
     S: Debug + Clone,
     R: Fn(S, S) -> bool,
 {
-    hint_section!("Is `{}` connex?", rel.name);
+    hint_section!("{}", connex_name(rel.name));
 
     let [a, b] = vars.eval();
 
@@ -117,7 +119,7 @@ where
     S: Debug + Clone,
     R: Fn(S, S) -> bool,
 {
-    hint_section!("Is `{}` transitive?", rel.name);
+    hint_section!("{}", transitive_name(rel.name));
 
     let [a, b, c] = vars.eval();
 
@@ -130,6 +132,55 @@ where
     ));
 }
 
+/// Asserts that the binary relation `rel` is [compatible] with the binary operation `op`.
+///
+/// For all `a`, `b`, `c` of `vars.set` it must hold:
+/// - `rel(a, b) --> rel(op(a, c), op(b, c))`
+///
+/// [compatible]: https://en.wikipedia.org/wiki/Ordered_group
+pub fn compatible<S, R, O>(vars: Vars<S, 3>, rel: Fun2<R>, op: Fun2<O>)
+where
+    S: Debug + Clone,
+    R: Fn(S, S) -> bool,
+    O: Fn(S, S) -> S,
+{
+    hint_section!("{}", compatible_name(rel.name, op.name));
+
+    let [a, b, c] = vars.eval();
+
+    ops::assert(ops::implies(
+        rel.eval(a.clone(), b.clone()),
+        rel.eval(op.eval(a, c.clone()), op.eval(b, c)),
+    ));
+}
+
+/// Asserts that the elements that are greater than or equal to `zero` regarding to the
+/// binary relation `rel` are [closed] under the binary operation `op`.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `(rel(zero, a) && rel(zero, b)) --> rel(zero, op(a, b))`
+///
+/// [closed]: https://en.wikipedia.org/wiki/Ordered_ring
+pub fn non_negative_closed<S, R, O>(vars: Vars<S, 2>, rel: Fun2<R>, op: Fun2<O>, zero: Elem<S>)
+where
+    S: Debug + Clone,
+    R: Fn(S, S) -> bool,
+    O: FnOnce(S, S) -> S,
+{
+    hint_section!("{}", non_negative_closed_name(rel.name, op.name, zero.name));
+
+    let [a, b] = vars.eval();
+    let zero = zero.eval();
+
+    ops::assert(ops::implies(
+        ops::and(
+            rel.eval(zero.clone(), a.clone()),
+            rel.eval(zero.clone(), b.clone()),
+        ),
+        rel.eval(zero, op.eval_once(a, b)),
+    ));
+}
+
 /// Asserts that the binary relation `rel` is a [partial equivalence relation].
 ///
 /// It must hold:
@@ -144,12 +195,9 @@ where
 {
     hint_section!("Is `{}` a partial equality relation?", rel.name);
 
-    let [a, b, c] = vars.elems;
-    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
-    let vars_3 = Vars::new(vars.set, [a, b, c]);
-
-    symmetric(vars_2, rel.as_ref());
-    transitive(vars_3, rel);
+    for law in partial_equivalence_laws(vars, rel) {
+        law.assert();
+    }
 }
 
 /// Asserts that the binary relation `rel` is an [equivalence relation].
@@ -167,14 +215,9 @@ where
 {
     hint_section!("Is `{}` an equality relation?", rel.name);
 
-    let [a, b, c] = vars.elems;
-    let vars_1 = Vars::new(vars.set, [a.clone()]);
-    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
-    let vars_3 = Vars::new(vars.set, [a, b, c]);
-
-    reflexive(vars_1, rel.as_ref());
-    symmetric(vars_2, rel.as_ref());
-    transitive(vars_3, rel);
+    for law in equivalence_laws(vars, rel) {
+        law.assert();
+    }
 }
 
 /// Asserts that the binary relation `rel` is a [partial order].
@@ -192,14 +235,9 @@ where
 {
     hint_section!("Is `{}` a partial order?", rel.name);
 
-    let [a, b, c] = vars.elems;
-    let vars_1 = Vars::new(vars.set, [a.clone()]);
-    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
-    let vars_3 = Vars::new(vars.set, [a, b, c]);
-
-    reflexive(vars_1, rel.as_ref());
-    antisymmetric(vars_2, rel.as_ref());
-    transitive(vars_3, rel);
+    for law in partial_order_laws(vars, rel) {
+        law.assert();
+    }
 }
 
 /// Asserts that the binary relation `rel` is a [total order].
@@ -217,20 +255,347 @@ where
 {
     hint_section!("Is `{}` a total order?", rel.name);
 
+    for law in total_order_laws(vars, rel) {
+        law.assert();
+    }
+}
+
+/// Asserts that `==` is an [equivalence relation] based on the implementation of [`Eq`].
+///
+/// This is a shortcut for [`equivalence`].
+///
+/// [equivalence relation]: https://en.wikipedia.org/wiki/Equivalence_relation
+pub fn equivalence_of<S>(vars: Vars<S, 3>)
+where
+    S: Debug + Clone + Eq,
+{
+    equivalence(vars, Fun2::infix("==", |x: S, y: S| x == y));
+}
+
+/// Asserts that `<=` is a [partial order] based on the implementation of [`PartialOrd`].
+///
+/// This is a shortcut for [`partial_order`].
+///
+/// `<=` is not reflexive for values that are not comparable with themselves, e.g. `NaN` of
+/// [`f32`] and [`f64`]. Hence `vars.set` must not contain such values.
+///
+/// [partial order]: https://en.wikipedia.org/wiki/Partially_ordered_set
+pub fn partial_order_of<S>(vars: Vars<S, 3>)
+where
+    S: Debug + Clone + PartialOrd,
+{
+    partial_order(vars, Fun2::infix("<=", |x: S, y: S| x <= y));
+}
+
+/// Asserts that `<=` is a [total order] based on the implementation of [`Ord`].
+///
+/// This is a shortcut for [`total_order`].
+///
+/// [total order]: https://en.wikipedia.org/wiki/Total_order
+pub fn total_order_of<S>(vars: Vars<S, 3>)
+where
+    S: Debug + Clone + Ord,
+{
+    total_order(vars, Fun2::infix("<=", |x: S, y: S| x <= y));
+}
+
+/// Returns the laws of a [partial equivalence relation] (see [`partial_equivalence`]).
+///
+/// [partial equivalence relation]: https://en.wikipedia.org/wiki/Partial_equivalence_relation
+pub fn partial_equivalence_laws<'a, S, R>(vars: Vars<'a, S, 3>, rel: Fun2<'a, R>) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    let rel = ("rel", &Rc::new(rel));
+
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![symmetric_law(vars_2, rel), transitive_law(vars_3, rel)]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Returns the laws of an [equivalence relation] (see [`equivalence`]).
+///
+/// [equivalence relation]: https://en.wikipedia.org/wiki/Equivalence_relation
+pub fn equivalence_laws<'a, S, R>(vars: Vars<'a, S, 3>, rel: Fun2<'a, R>) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    let rel = ("rel", &Rc::new(rel));
+
+    let [a, b, c] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![
+        reflexive_law(vars_1, rel),
+        symmetric_law(vars_2, rel),
+        transitive_law(vars_3, rel),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns the laws of a [partial order] (see [`partial_order`]).
+///
+/// [partial order]: https://en.wikipedia.org/wiki/Partially_ordered_set
+pub fn partial_order_laws<'a, S, R>(vars: Vars<'a, S, 3>, rel: Fun2<'a, R>) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    let rel = ("rel", &Rc::new(rel));
+
+    let [a, b, c] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![
+        reflexive_law(vars_1, rel),
+        antisymmetric_law(vars_2, rel),
+        transitive_law(vars_3, rel),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns the laws of a [total order] (see [`total_order`]).
+///
+/// [total order]: https://en.wikipedia.org/wiki/Total_order
+pub fn total_order_laws<'a, S, R>(vars: Vars<'a, S, 3>, rel: Fun2<'a, R>) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    total_order_laws_rc(vars, ("rel", &Rc::new(rel)))
+}
+
+pub(crate) fn total_order_laws_rc<'a, S, R>(
+    vars: Vars<'a, S, 3>,
+    rel: (&str, &Rc<Fun2<'a, R>>),
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
     let [a, b, c] = vars.elems;
     let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
     let vars_3 = Vars::new(vars.set, [a, b, c]);
 
-    connex(vars_2.clone(), rel.as_ref());
-    antisymmetric(vars_2, rel.as_ref());
-    transitive(vars_3, rel);
+    vec![
+        connex_law(vars_2.clone(), rel),
+        antisymmetric_law(vars_2, rel),
+        transitive_law(vars_3, rel),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn reflexive_law<'a, S, R>(
+    vars: Vars<'a, S, 1>,
+    (rel_id, rel): (&str, &Rc<Fun2<'a, R>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    law::build(format!("{}_is_reflexive", rel_id), |id| {
+        let [a] = law::var_names(&vars);
+        let name = reflexive_name(rel.name);
+        let statement = rel.label(a, a).to_string();
+        let rel = Rc::clone(rel);
+
+        Law::new(id, name, statement, 1, move || {
+            reflexive(vars.clone(), Fun2::as_ref(&rel))
+        })
+    })
+}
+
+fn symmetric_law<'a, S, R>(
+    vars: Vars<'a, S, 2>,
+    (rel_id, rel): (&str, &Rc<Fun2<'a, R>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    law::build(format!("{}_is_symmetric", rel_id), |id| {
+        let [a, b] = law::var_names(&vars);
+        let name = symmetric_name(rel.name);
+        let statement = law::implies(rel.label(a, b), rel.label(b, a));
+        let rel = Rc::clone(rel);
+
+        Law::new(id, name, statement, 2, move || {
+            symmetric(vars.clone(), Fun2::as_ref(&rel))
+        })
+    })
+}
+
+fn antisymmetric_law<'a, S, R>(
+    vars: Vars<'a, S, 2>,
+    (rel_id, rel): (&str, &Rc<Fun2<'a, R>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    law::build(format!("{}_is_antisymmetric", rel_id), |id| {
+        let [a, b] = law::var_names(&vars);
+        let name = antisymmetric_name(rel.name);
+        let statement = law::implies(
+            law::and(law::ne(a, b), rel.label(a, b)),
+            law::not(rel.label(b, a)),
+        );
+        let rel = Rc::clone(rel);
+
+        Law::new(id, name, statement, 2, move || {
+            antisymmetric(vars.clone(), Fun2::as_ref(&rel))
+        })
+    })
+}
+
+fn connex_law<'a, S, R>(
+    vars: Vars<'a, S, 2>,
+    (rel_id, rel): (&str, &Rc<Fun2<'a, R>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    law::build(format!("{}_is_connex", rel_id), |id| {
+        let [a, b] = law::var_names(&vars);
+        let name = connex_name(rel.name);
+        let statement = law::or(rel.label(a, b), rel.label(b, a));
+        let rel = Rc::clone(rel);
+
+        Law::new(id, name, statement, 2, move || {
+            connex(vars.clone(), Fun2::as_ref(&rel))
+        })
+    })
+}
+
+fn transitive_law<'a, S, R>(
+    vars: Vars<'a, S, 3>,
+    (rel_id, rel): (&str, &Rc<Fun2<'a, R>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+{
+    law::build(format!("{}_is_transitive", rel_id), |id| {
+        let [a, b, c] = law::var_names(&vars);
+        let name = transitive_name(rel.name);
+        let statement = law::implies(law::and(rel.label(a, b), rel.label(b, c)), rel.label(a, c));
+        let rel = Rc::clone(rel);
+
+        Law::new(id, name, statement, 3, move || {
+            transitive(vars.clone(), Fun2::as_ref(&rel))
+        })
+    })
+}
+
+pub(crate) fn compatible_law<'a, S, R, O>(
+    vars: Vars<'a, S, 3>,
+    (rel_id, rel): (&str, &Rc<Fun2<'a, R>>),
+    (op_id, op): (&str, &Rc<Fun2<'a, O>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    law::build(format!("{}_is_compatible_with_{}", rel_id, op_id), |id| {
+        let [a, b, c] = law::var_names(&vars);
+        let name = compatible_name(rel.name, op.name);
+        let statement = law::implies(rel.label(a, b), rel.label(op.label(a, c), op.label(b, c)));
+        let rel = Rc::clone(rel);
+        let op = Rc::clone(op);
+
+        Law::new(id, name, statement, 3, move || {
+            compatible(vars.clone(), Fun2::as_ref(&rel), Fun2::as_ref(&op))
+        })
+    })
+}
+
+pub(crate) fn non_negative_closed_law<'a, S, R, O>(
+    vars: Vars<'a, S, 2>,
+    (_, rel): (&str, &Rc<Fun2<'a, R>>),
+    (op_id, op): (&str, &Rc<Fun2<'a, O>>),
+    (_, zero): (&str, Elem<'a, S>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Fn(S, S) -> bool + 'a,
+    O: Fn(S, S) -> S + 'a,
+{
+    law::build(format!("{}_is_non_negative_closed", op_id), |id| {
+        let [a, b] = law::var_names(&vars);
+        let name = non_negative_closed_name(rel.name, op.name, zero.name);
+        let statement = law::implies(
+            law::and(rel.label(zero.name, a), rel.label(zero.name, b)),
+            rel.label(zero.name, op.label(a, b)),
+        );
+        let rel = Rc::clone(rel);
+        let op = Rc::clone(op);
+
+        Law::new(id, name, statement, 2, move || {
+            non_negative_closed(
+                vars.clone(),
+                Fun2::as_ref(&rel),
+                Fun2::as_ref(&op),
+                zero.clone(),
+            )
+        })
+    })
+}
+
+fn reflexive_name(rel: &str) -> String {
+    format!("Is `{}` reflexive?", rel)
+}
+
+fn symmetric_name(rel: &str) -> String {
+    format!("Is `{}` symmetric?", rel)
+}
+
+fn antisymmetric_name(rel: &str) -> String {
+    format!("Is `{}` antisymmetric?", rel)
+}
+
+fn connex_name(rel: &str) -> String {
+    format!("Is `{}` connex?", rel)
+}
+
+fn transitive_name(rel: &str) -> String {
+    format!("Is `{}` transitive?", rel)
+}
+
+fn compatible_name(rel: &str, op: &str) -> String {
+    format!("Is `{}` compatible with `{}`?", rel, op)
+}
+
+fn non_negative_closed_name(rel: &str, op: &str, zero: &str) -> String {
+    format!(
+        "Is `{}` closed for elements not less than `{}` regarding to `{}`?",
+        op, zero, rel
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use dicetest::prelude::*;
 
-    use crate::{props, Fun2, Set};
+    use crate::{props, Elem, Fun2, Set, Vars};
 
     #[test]
     fn reflexive_example() {
@@ -333,4 +698,87 @@ mod tests {
             props::binrel::total_order(vars, rel);
         })
     }
+
+    #[test]
+    fn compatible_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let rel = Fun2::infix("<=", |x, y| x <= y);
+            let op = Fun2::infix("+", |x, y| x + y);
+            props::binrel::compatible(vars, rel, op);
+        })
+    }
+
+    #[test]
+    fn non_negative_closed_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let rel = Fun2::infix("<=", |x, y| x <= y);
+            let op = Fun2::infix("*", |x, y| x * y);
+            let zero = Elem::new("zero", 0);
+            props::binrel::non_negative_closed(vars, rel, op, zero);
+        })
+    }
+
+    #[test]
+    fn equivalence_of_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("String", dice::string(dice::char(), ..));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            props::binrel::equivalence_of(vars);
+        })
+    }
+
+    #[test]
+    fn partial_order_of_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("f32", dice::f32(..));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            props::binrel::partial_order_of(vars);
+        })
+    }
+
+    #[test]
+    fn total_order_of_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("char", dice::char());
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            props::binrel::total_order_of(vars);
+        })
+    }
+
+    #[test]
+    fn total_order_laws_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("f32", dice::any_f32());
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let rel = Fun2::infix("<=", |x: f32, y: f32| x <= y);
+            let laws = props::binrel::total_order_laws(vars, rel);
+
+            let statements = laws
+                .iter()
+                .map(|law| law.statement.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                statements,
+                [
+                    "((x <= y) || (y <= x))",
+                    "(((x != y) && (x <= y)) --> !((y <= x)))",
+                    "(((x <= y) && (y <= z)) --> (x <= z))",
+                ]
+            );
+
+            let nan = Vars::new("f32", [Elem::new("x", f32::NAN), Elem::new("y", 1.0)]);
+            let rel = Fun2::infix("<=", |x: f32, y: f32| x <= y);
+            let failure = props::binrel::total_order_laws(
+                Vars::new(nan.set, [nan.elems[0], nan.elems[1], nan.elems[1]]),
+                rel,
+            )[0]
+            .check()
+            .unwrap_err();
+            assert_eq!(failure.section.as_deref(), Some("Is `<=` connex?"));
+        })
+    }
 }
@@ -0,0 +1,417 @@
+//! Properties for [codecs], i.e. pairs of an encoding function `encode` and a decoding function
+//! `decode` that returns a [`Result`].
+//!
+//! The results of `decode` are compared via [`Result::ok`], hence the error type doesn't need
+//! to implement [`PartialEq`].
+//!
+//! Whether each value has exactly one encoding can be checked additionally with [`canonical`].
+//!
+//! Incremental decoders for byte streams can be checked with [`chunk_invariant`].
+//!
+//! With the feature `serde`, both functions can be built from a serde `Serializer` and
+//! `Deserializer` via `serializer` and `deserializer`.
+//!
+//! [codecs]: https://en.wikipedia.org/wiki/Codec
+
+use dicetest::{hint, hint_section};
+use std::fmt::Debug;
+
+use crate::{ops, Elem, Eval, Fun1, Fun2, Vars};
+
+/// Asserts that `decode` is the left inverse of `encode`.
+///
+/// For all `x` of `vars.set` it must hold:
+/// - `decode(encode(x)) == Ok(x)`
+pub fn round_trip<S, B, E, D, Err>(vars: Vars<S, 1>, encode: Fun1<E>, decode: Fun1<D>)
+where
+    S: Debug + Clone + PartialEq,
+    B: Debug,
+    Err: Debug,
+    E: FnOnce(S) -> B,
+    D: FnOnce(B) -> Result<S, Err>,
+{
+    hint_section!(
+        "Does `{}` decode the results of `{}`?",
+        decode.name,
+        encode.name,
+    );
+
+    let [x] = vars.eval();
+    let ok = Fun1::postfix(".ok()", Result::ok);
+    let some = Fun1::new("Some", Some);
+
+    ops::assert(ops::eq(
+        ok.eval(decode.eval_once(encode.eval_once(x.clone())))
+            .as_ref(),
+        some.eval(x).as_ref(),
+    ));
+}
+
+/// Asserts that `encode` is deterministic.
+///
+/// For all `x` of `vars.set` it must hold:
+/// - `encode(x) == encode(x)`
+pub fn deterministic<S, B, E>(vars: Vars<S, 1>, encode: Fun1<E>)
+where
+    S: Debug + Clone,
+    B: Debug + PartialEq,
+    E: Fn(S) -> B,
+{
+    hint_section!("Is `{}` deterministic?", encode.name);
+
+    let [x] = vars.eval();
+
+    ops::assert(ops::eq(
+        encode.eval(x.clone()).as_ref(),
+        encode.eval(x).as_ref(),
+    ));
+}
+
+/// Asserts that `encode` is the left inverse of `decode` for all valid encodings, i.e. that
+/// each value has exactly one encoding.
+///
+/// For all `b` of `vars.set` it must hold:
+/// - if `decode(b)` returns `Ok(x)`, then `encode(x) == b`
+pub fn canonical<S, B, E, D, Err>(vars: Vars<B, 1>, encode: Fun1<E>, decode: Fun1<D>)
+where
+    S: Debug,
+    B: Debug + Clone + PartialEq,
+    Err: Debug,
+    E: FnOnce(S) -> B,
+    D: FnOnce(B) -> Result<S, Err>,
+{
+    hint_section!(
+        "Is `{}` canonical regarding to `{}`?",
+        encode.name,
+        decode.name
+    );
+
+    let [b] = vars.eval();
+    let unwrap = Fun1::postfix(".unwrap()", Result::unwrap);
+
+    let x = decode.eval_once(b.clone());
+
+    if x.value.is_ok() {
+        ops::assert(ops::eq(
+            encode.eval_once(unwrap.eval(x)).as_ref(),
+            b.as_ref(),
+        ));
+    }
+}
+
+/// Asserts that `(encode, decode)` is a valid codec.
+///
+/// It must hold:
+/// - `decode` is the left inverse of `encode` ([`round_trip`])
+/// - `encode` is deterministic ([`deterministic`])
+///
+/// This doesn't check whether each value has exactly one encoding, use [`canonical`] for that.
+pub fn codec<S, B, E, D, Err>(vars: Vars<S, 1>, encode: Fun1<E>, decode: Fun1<D>)
+where
+    S: Debug + Clone + PartialEq,
+    B: Debug + PartialEq,
+    Err: Debug,
+    E: Fn(S) -> B,
+    D: FnOnce(B) -> Result<S, Err>,
+{
+    hint_section!(
+        "Is `({}, {})` a codec for `{}`?",
+        encode.name,
+        decode.name,
+        vars.set,
+    );
+
+    round_trip(vars.clone(), encode.as_ref(), decode);
+    deterministic(vars, encode);
+}
+
+/// Asserts that the incremental decoder `feed` decodes a byte stream independently of how the
+/// stream is split into chunks.
+///
+/// The messages are encoded with `encode` and concatenated to a stream. The stream is split
+/// into chunks whose sizes are derived from the generated boundaries, i.e. each boundary selects
+/// the size of the next chunk modulo the number of remaining bytes plus one. Hence chunks may be
+/// empty and the remaining bytes form the last chunk. The chunks are fed in order to `feed`,
+/// together with a decoder that starts as `decoder`, and `feed` returns the messages completed
+/// by the given chunk. The chunks are logged via hints.
+///
+/// Small boundaries are more likely to split a frame header, e.g. `dice::vec(dice::usize(..4), ..)`.
+///
+/// For all `xs` of `vars.set` and `bs` of `boundary_vars.set` it must hold:
+/// - `(decode_whole(xs)).ok() == Some(xs)`
+/// - `(decode_chunks(xs, bs)).ok() == (decode_whole(xs)).ok()`
+pub fn chunk_invariant<S, D, Err, E, F>(
+    vars: Vars<Vec<S>, 1>,
+    boundary_vars: Vars<Vec<usize>, 1>,
+    encode: Fun1<E>,
+    decoder: Elem<D>,
+    feed: Fun2<F>,
+) where
+    S: Debug + Clone + PartialEq,
+    D: Debug + Clone,
+    Err: Debug,
+    E: Fn(S) -> Vec<u8>,
+    F: Fn(&mut D, &[u8]) -> Result<Vec<S>, Err>,
+{
+    hint_section!(
+        "Does `{}` decode the results of `{}` independently of the chunks?",
+        feed.name,
+        encode.name,
+    );
+
+    let [xs] = vars.eval();
+    let [bs] = boundary_vars.eval();
+    let decoder = decoder.eval();
+
+    let stream = xs
+        .value
+        .iter()
+        .cloned()
+        .flat_map(encode.f())
+        .collect::<Vec<u8>>();
+    hint!("stream = {:?}", stream);
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    for &b in &bs.value {
+        let end = start + b % (stream.len() - start + 1);
+        chunks.push(start..end);
+        start = end;
+    }
+    chunks.push(start..stream.len());
+    hint!("chunks = {:?}", chunks);
+
+    let whole_label = format!("(decode_whole({})).ok()", xs.label);
+    let whole = Eval {
+        label: whole_label.as_str(),
+        value: decode_stream(decoder.value.clone(), [&stream[..]], feed.f()).ok(),
+    };
+    hint!("{} = {:?}", whole.label, whole.value);
+
+    let chunked_label = format!("(decode_chunks({}, {})).ok()", xs.label, bs.label);
+    let chunked = Eval {
+        label: chunked_label.as_str(),
+        value: decode_stream(
+            decoder.value,
+            chunks.into_iter().map(|chunk| &stream[chunk]),
+            feed.f(),
+        )
+        .ok(),
+    };
+    hint!("{} = {:?}", chunked.label, chunked.value);
+
+    let some = Fun1::new("Some", Some);
+
+    ops::assert(ops::eq(whole.as_ref(), some.eval(xs).as_ref()));
+    ops::assert(ops::eq(chunked.as_ref(), whole.as_ref()));
+}
+
+fn decode_stream<'a, S, D, Err, F>(
+    mut decoder: D,
+    chunks: impl IntoIterator<Item = &'a [u8]>,
+    feed: &F,
+) -> Result<Vec<S>, Err>
+where
+    F: Fn(&mut D, &[u8]) -> Result<Vec<S>, Err>,
+{
+    let mut messages = Vec::new();
+    for chunk in chunks {
+        messages.extend(feed(&mut decoder, chunk)?);
+    }
+    Ok(messages)
+}
+
+/// Returns an encoding function with the given name that serializes its argument with the
+/// serializer created by `new` and returns the output via `into_inner`.
+///
+/// # Panics
+///
+/// The function panics if the serialization fails.
+///
+/// # Examples
+///
+/// ```
+/// use diceprop::{props, Set};
+/// use dicetest::prelude::*;
+/// use std::io::Cursor;
+///
+/// Dicetest::repeatedly().run(|mut fate| {
+///     let set = Set::new("Vec<String>", dice::vec(dice::string(dice::char(), ..), ..));
+///     let vars = fate.roll(set.vars(["x"]));
+///     let encode = props::codec::serializer(
+///         "to_json",
+///         || serde_json::Serializer::new(Vec::new()),
+///         serde_json::Serializer::into_inner,
+///     );
+///     let decode = props::codec::deserializer(
+///         "from_json",
+///         |bytes: Vec<u8>| serde_json::Deserializer::from_reader(Cursor::new(bytes)),
+///         serde_json::Deserializer::end,
+///     );
+///     props::codec::codec(vars, encode, decode);
+/// })
+/// ```
+#[cfg(feature = "serde")]
+pub fn serializer<'a, S, Z, B, N, I>(
+    name: &'a str,
+    new: N,
+    into_inner: I,
+) -> Fun1<'a, impl Fn(S) -> B>
+where
+    S: serde::Serialize,
+    N: Fn() -> Z,
+    I: Fn(Z) -> B,
+    for<'z> &'z mut Z: serde::Serializer,
+{
+    Fun1::new(name, move |value: S| {
+        let mut serializer = new();
+        if let Err(err) = value.serialize(&mut serializer) {
+            panic!("serialization failed: {}", err);
+        }
+        into_inner(serializer)
+    })
+}
+
+/// Returns a decoding function with the given name that deserializes its argument with the
+/// deserializer created by `new` and rejects trailing input via `end`, e.g.
+/// `serde_json::Deserializer::end`. Errors are converted to strings.
+///
+/// See [`serializer`] for an example.
+#[cfg(feature = "serde")]
+pub fn deserializer<'a, S, Z, B, N, F, FErr>(
+    name: &'a str,
+    new: N,
+    end: F,
+) -> Fun1<'a, impl Fn(B) -> Result<S, String>>
+where
+    S: serde::de::DeserializeOwned,
+    N: Fn(B) -> Z,
+    F: Fn(&mut Z) -> Result<(), FErr>,
+    FErr: std::fmt::Display,
+    for<'z> &'z mut Z: serde::Deserializer<'static>,
+{
+    Fun1::new(name, move |input: B| {
+        let mut deserializer = new(input);
+        let value = S::deserialize(&mut deserializer).map_err(|err| err.to_string())?;
+        end(&mut deserializer).map_err(|err| err.to_string())?;
+        Ok(value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{check, props, Elem, Fun1, Fun2, Set, Vars};
+
+    fn to_be_bytes<'a>() -> Fun1<'a, impl Fn(u16) -> Vec<u8>> {
+        Fun1::new("to_be_bytes", |x: u16| x.to_be_bytes().to_vec())
+    }
+
+    fn from_be_bytes<'a>() -> Fun1<'a, impl Fn(Vec<u8>) -> Result<u16, String>> {
+        Fun1::new("from_be_bytes", |bytes: Vec<u8>| match bytes[..] {
+            [a, b] => Ok(u16::from_be_bytes([a, b])),
+            _ => Err(format!("expected 2 bytes, got {}", bytes.len())),
+        })
+    }
+
+    fn to_frame<'a>() -> Fun1<'a, impl Fn(Vec<u8>) -> Vec<u8>> {
+        Fun1::new("to_frame", |payload: Vec<u8>| {
+            let mut frame = (payload.len() as u16).to_be_bytes().to_vec();
+            frame.extend(payload);
+            frame
+        })
+    }
+
+    fn feed_frames(buffer: &mut Vec<u8>, chunk: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+        buffer.extend_from_slice(chunk);
+        let mut frames = Vec::new();
+        while let [a, b, ref rest @ ..] = buffer[..] {
+            let len = u16::from_be_bytes([a, b]) as usize;
+            if rest.len() < len {
+                break;
+            }
+            frames.push(rest[..len].to_vec());
+            buffer.drain(..2 + len);
+        }
+        Ok(frames)
+    }
+
+    #[test]
+    fn codec_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("u16", dice::u16(..));
+            let vars = fate.roll(set.vars(["x"]));
+            props::codec::codec(vars, to_be_bytes(), from_be_bytes());
+        })
+    }
+
+    #[test]
+    fn canonical_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..4));
+            let vars = fate.roll(set.vars(["b"]));
+            props::codec::canonical(vars, to_be_bytes(), from_be_bytes());
+        })
+    }
+
+    #[test]
+    fn canonical_detects_leading_zeros() {
+        let vars = Vars::new("String", [Elem::new("b", "007".to_string())]);
+        let encode = Fun1::new("to_string", |x: u8| x.to_string());
+        let decode = Fun1::new("parse", |s: String| s.parse::<u8>());
+
+        let failure = check(|| props::codec::canonical(vars, encode, decode)).unwrap_err();
+
+        assert_eq!(failure.assertion, "(to_string((parse(b)).unwrap()) == b)");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializer_rejects_trailing_input() {
+        let decode = props::codec::deserializer(
+            "from_json",
+            |bytes: Vec<u8>| serde_json::Deserializer::from_reader(std::io::Cursor::new(bytes)),
+            serde_json::Deserializer::end,
+        );
+
+        assert_eq!((decode.f())(b"42".to_vec()), Ok(42u8));
+        assert!((decode.f())(b"42 43".to_vec()).is_err());
+    }
+
+    #[test]
+    fn chunk_invariant_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("Vec<Vec<u8>>", dice::vec(dice::vec(dice::u8(..), ..8), ..4));
+            let boundary_set = Set::new("Vec<usize>", dice::vec(dice::usize(..4), ..));
+            let vars = fate.roll(set.vars(["xs"]));
+            let boundary_vars = fate.roll(boundary_set.vars(["bs"]));
+            let decoder = Elem::new("empty", Vec::new());
+            let feed = Fun2::new("feed_frames", feed_frames);
+            props::codec::chunk_invariant(vars, boundary_vars, to_frame(), decoder, feed);
+        })
+    }
+
+    #[test]
+    fn chunk_invariant_detects_split_header() {
+        let vars = Vars::new("Vec<Vec<u8>>", [Elem::new("xs", vec![vec![1, 2, 3]])]);
+        let boundary_vars = Vars::new("Vec<usize>", [Elem::new("bs", vec![1])]);
+        let decoder = Elem::new("empty", Vec::new());
+        // Expects each chunk to start with a complete header
+        let feed = Fun2::new("feed_frames", |buffer: &mut Vec<u8>, chunk: &[u8]| {
+            if buffer.is_empty() && chunk.len() < 2 {
+                return Ok(Vec::new());
+            }
+            feed_frames(buffer, chunk)
+        });
+
+        let failure =
+            check(|| props::codec::chunk_invariant(vars, boundary_vars, to_frame(), decoder, feed))
+                .unwrap_err();
+
+        assert_eq!(
+            failure.assertion,
+            "((decode_chunks(xs, bs)).ok() == (decode_whole(xs)).ok())"
+        );
+    }
+}
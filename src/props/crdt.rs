@@ -0,0 +1,213 @@
+//! Properties for [conflict-free replicated data types] (CRDTs).
+//!
+//! Replicas of a CRDT may receive updates in different orders, but must converge to the same
+//! state. The delivery orders are derived from generated choices of type `Vec<usize>`, i.e.
+//! each choice selects the next element modulo the number of remaining elements. Missing
+//! choices select the first remaining element. The resulting delivery orders are logged via
+//! hints.
+//!
+//! The semilattice laws of the merge function of a state-based CRDT can be asserted separately,
+//! e.g. with [`binop::associative`] and [`binop::commutative`].
+//!
+//! [conflict-free replicated data types]: https://en.wikipedia.org/wiki/Conflict-free_replicated_data_type
+//! [`binop::associative`]: crate::props::binop::associative
+//! [`binop::commutative`]: crate::props::binop::commutative
+
+use dicetest::{hint, hint_section};
+use std::fmt::Debug;
+use std::iter;
+
+use crate::{ops, Elem, Fun2, Fun3, Vars};
+
+/// Asserts that the replicas of a state-based CRDT converge regardless of the delivery order
+/// of the updates and the merge order of the replicas.
+///
+/// The updates are shuffled and distributed round-robin to `replicas` replicas that start with
+/// `init`. Each replica applies its updates with `update`, then all replicas are merged with
+/// `merge` in a shuffled order. The result must be equal to the state of a single replica that
+/// received all updates in their original order.
+///
+/// For all `us` of `vars.set` and `ps` of `order_vars.set` it must hold:
+/// - `converge(us, ps) == fold(update)(us, init)`
+///
+/// # Panics
+///
+/// Panics if `replicas` is zero.
+pub fn state_based_convergence<S, U, A, M, const N: usize>(
+    vars: Vars<Vec<U>, 1>,
+    order_vars: Vars<Vec<usize>, N>,
+    init: Elem<S>,
+    update: Fun2<A>,
+    merge: Fun2<M>,
+    replicas: usize,
+) where
+    S: Debug + Clone + PartialEq,
+    U: Debug + Clone,
+    A: Fn(S, U) -> S,
+    M: Fn(S, S) -> S,
+{
+    assert!(replicas > 0, "at least one replica is required");
+
+    hint_section!(
+        "Do the replicas converge with `{}` and `{}`?",
+        update.name,
+        merge.name,
+    );
+
+    let [us] = vars.eval();
+    let init = init.eval();
+
+    let fold_name = format!("fold({})", update.name);
+    let fold = Fun2::new(&fold_name, |us: &Vec<U>, init: &S| {
+        us.iter().cloned().fold(init.clone(), update.f())
+    });
+    let fold = fold.eval(us.as_ref(), init.as_ref());
+
+    let converge = Fun2::new("converge", |us: &Vec<U>, ps: &Vec<usize>| {
+        let mut choices = choices(ps);
+        let delivery = permutation(us.len(), &mut choices);
+        hint!("delivery order = {:?}", delivery);
+
+        let mut states = vec![init.value.clone(); replicas];
+        for (i, &index) in delivery.iter().enumerate() {
+            let replica = i % replicas;
+            hint!("replica {} receives {:?}", replica, us[index]);
+            let state = states[replica].clone();
+            states[replica] = (update.f())(state, us[index].clone());
+        }
+
+        let merge_order = permutation(replicas, &mut choices);
+        hint!("merge order = {:?}", merge_order);
+
+        merge_order
+            .into_iter()
+            .map(|replica| states[replica].clone())
+            .reduce(merge.f())
+            .unwrap()
+    });
+
+    for ps in order_vars.eval() {
+        hint_section!("Delivery with `{}`:", ps.label);
+
+        let converge = converge.eval(us.as_ref(), ps.as_ref());
+
+        ops::assert(ops::eq(converge.as_ref(), fold.as_ref()));
+    }
+}
+
+/// Asserts that the concurrent operations of an op-based CRDT commute, i.e. that applying them
+/// via `apply` in any delivery order yields the same state.
+///
+/// For all `s` of `vars.set`, `os` of `op_vars.set` and `ps` of `order_vars.set` it must hold:
+/// - `deliver(s, os, ps) == fold(apply)(os, s)`
+pub fn op_based_commutativity<S, O, A>(
+    vars: Vars<S, 1>,
+    op_vars: Vars<Vec<O>, 1>,
+    order_vars: Vars<Vec<usize>, 1>,
+    apply: Fun2<A>,
+) where
+    S: Debug + Clone + PartialEq,
+    O: Debug + Clone,
+    A: Fn(S, O) -> S,
+{
+    hint_section!("Do the operations commute with `{}`?", apply.name);
+
+    let [s] = vars.eval();
+    let [os] = op_vars.eval();
+    let [ps] = order_vars.eval();
+
+    let deliver = Fun3::new("deliver", |s: &S, os: &Vec<O>, ps: &Vec<usize>| {
+        let delivery = permutation(os.len(), &mut choices(ps));
+        hint!("delivery order = {:?}", delivery);
+
+        delivery
+            .into_iter()
+            .map(|index| os[index].clone())
+            .fold(s.clone(), apply.f())
+    });
+    let deliver = deliver.eval(s.as_ref(), os.as_ref(), ps.as_ref());
+
+    let fold_name = format!("fold({})", apply.name);
+    let fold = Fun2::new(&fold_name, |os: Vec<O>, s: S| {
+        os.into_iter().fold(s, apply.f())
+    });
+    let fold = fold.eval_once(os, s);
+
+    ops::assert(ops::eq(deliver.as_ref(), fold.as_ref()));
+}
+
+fn choices(choices: &[usize]) -> impl Iterator<Item = usize> + '_ {
+    choices.iter().copied().chain(iter::repeat(0))
+}
+
+fn permutation(n: usize, choices: &mut impl Iterator<Item = usize>) -> Vec<usize> {
+    let mut remaining = (0..n).collect::<Vec<_>>();
+    (0..n)
+        .map(|_| {
+            let choice = choices.next().unwrap_or(0);
+            remaining.remove(choice % remaining.len())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+    use std::collections::BTreeSet;
+
+    use crate::{check, props, Elem, Fun2, Set, Vars};
+
+    #[test]
+    fn state_based_convergence_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..));
+            let order_set = Set::new("Vec<usize>", dice::vec(dice::usize(..), ..));
+            let vars = fate.roll(set.vars(["us"]));
+            let order_vars = fate.roll(order_set.vars(["ps", "qs"]));
+            let init = Elem::new("empty", BTreeSet::new());
+            let update = Fun2::new("insert", |mut s: BTreeSet<u8>, u| {
+                s.insert(u);
+                s
+            });
+            let merge = Fun2::new("union", |s: BTreeSet<u8>, t: BTreeSet<u8>| &s | &t);
+            props::crdt::state_based_convergence(vars, order_vars, init, update, merge, 3);
+        })
+    }
+
+    #[test]
+    fn state_based_convergence_detects_lost_update() {
+        let vars = Vars::new("Vec<u8>", [Elem::new("us", vec![1, 2])]);
+        let order_vars = Vars::new(
+            "Vec<usize>",
+            [Elem::new("ps", vec![]), Elem::new("qs", vec![1])],
+        );
+        let init = Elem::new("none", None);
+        let update = Fun2::new("assign", |_, u| Some(u));
+        let merge = Fun2::new("right", |_, t: Option<u8>| t);
+
+        let failure = check(|| {
+            props::crdt::state_based_convergence(vars, order_vars, init, update, merge, 1)
+        })
+        .unwrap_err();
+
+        assert_eq!(failure.section.unwrap(), "Delivery with `qs`:");
+        assert_eq!(
+            failure.assertion,
+            "(converge(us, qs) == fold(assign)(us, none))"
+        );
+    }
+
+    #[test]
+    fn op_based_commutativity_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-1000,1000]", dice::i64(-1000..=1000));
+            let op_set = Set::new("Vec<i64>", dice::vec(dice::i64(-1000..=1000), ..));
+            let order_set = Set::new("Vec<usize>", dice::vec(dice::usize(..), ..));
+            let vars = fate.roll(set.vars(["s"]));
+            let op_vars = fate.roll(op_set.vars(["os"]));
+            let order_vars = fate.roll(order_set.vars(["ps"]));
+            let apply = Fun2::new("add", |s, o| s + o);
+            props::crdt::op_based_commutativity(vars, op_vars, order_vars, apply);
+        })
+    }
+}
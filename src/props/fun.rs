@@ -4,7 +4,9 @@
 
 use dicetest::hint_section;
 use std::fmt::Debug;
+use std::rc::Rc;
 
+use crate::law::{self, Law};
 use crate::{ops, Fun1, Fun2, Vars};
 
 /// Asserts that the function `f` is [idempotent].
@@ -148,7 +150,7 @@ where
     R: Debug + PartialEq,
     O: Fn(S, S) -> R,
 {
-    hint_section!("Is `{}` commutative?", f.name);
+    hint_section!("{}", commutative_name(f.name));
 
     let [a, b] = vars.eval();
 
@@ -158,6 +160,31 @@ where
     ));
 }
 
+pub(crate) fn commutative_law<'a, S, R, O>(
+    vars: Vars<'a, S, 2>,
+    (f_id, f): (&str, &Rc<Fun2<'a, O>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    R: Debug + PartialEq,
+    O: Fn(S, S) -> R + 'a,
+{
+    law::build(format!("{}_is_commutative", f_id), |id| {
+        let [a, b] = law::var_names(&vars);
+        let name = commutative_name(f.name);
+        let statement = law::eq(f.label(a, b), f.label(b, a));
+        let f = Rc::clone(f);
+
+        Law::new(id, name, statement, 2, move || {
+            commutative(vars.clone(), Fun2::as_ref(&f))
+        })
+    })
+}
+
+fn commutative_name(f: &str) -> String {
+    format!("Is `{}` commutative?", f)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{props, Fun1, Fun2, Set};
@@ -0,0 +1,227 @@
+//! Properties for [functors], i.e. containers with a `map` function.
+//!
+//! Rust has no higher-kinded types, hence `map` is represented by a [`Fun2`] that takes a
+//! container and a reference to a function `&dyn Fn(A) -> A`. The mapped functions `f` and `g`
+//! map elements of type `A` to elements of the same type.
+//!
+//! This is a restriction: a closure can't be generic, so a single `map` can't be applied to
+//! `f: A -> B`, `g: B -> C` and `g ∘ f: A -> C` at once. Maps that change the element type can
+//! only be checked with their endofunctions, e.g. `Option::map` with functions from `u8` to
+//! `u8`.
+//!
+//! [functors]: https://en.wikipedia.org/wiki/Functor_(functional_programming)
+
+use dicetest::hint_section;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::law::{self, Law};
+use crate::{ops, Fun1, Fun2, Vars};
+
+/// Asserts that the function `map` preserves the identity function.
+///
+/// For all `xs` of `vars.set` it must hold:
+/// - `map(id)(xs) == xs`
+pub fn identity<C, A, M>(vars: Vars<C, 1>, map: Fun2<M>)
+where
+    C: Debug + Clone + PartialEq,
+    M: Fn(C, &dyn Fn(A) -> A) -> C,
+{
+    hint_section!("{}", identity_name(map.name));
+
+    let [xs] = vars.eval();
+    let map_id_name = format!("{}(id)", map.name);
+    let map_id = Fun1::new(&map_id_name, |xs| (map.f())(xs, &|a| a));
+
+    ops::assert(ops::eq(map_id.eval(xs.clone()).as_ref(), xs.as_ref()));
+}
+
+/// Asserts that the function `map` preserves the composition of the functions `f` and `g`.
+///
+/// For all `xs` of `vars.set` it must hold:
+/// - `map(g ∘ f)(xs) == map(g)(map(f)(xs))`
+pub fn composition<C, A, M, F, G>(vars: Vars<C, 1>, map: Fun2<M>, f: Fun1<F>, g: Fun1<G>)
+where
+    C: Debug + Clone + PartialEq,
+    M: Fn(C, &dyn Fn(A) -> A) -> C,
+    F: Fn(A) -> A,
+    G: Fn(A) -> A,
+{
+    hint_section!("{}", composition_name(map.name, f.name, g.name));
+
+    let [xs] = vars.eval();
+    let map_g_f_name = format!("{}({} ∘ {})", map.name, g.name, f.name);
+    let map_g_f = Fun1::new(&map_g_f_name, |xs| (map.f())(xs, &|a| (g.f())((f.f())(a))));
+    let map_f_name = format!("{}({})", map.name, f.name);
+    let map_f = Fun1::new(&map_f_name, |xs| (map.f())(xs, f.f()));
+    let map_g_name = format!("{}({})", map.name, g.name);
+    let map_g = Fun1::new(&map_g_name, |xs| (map.f())(xs, g.f()));
+
+    ops::assert(ops::eq(
+        map_g_f.eval(xs.clone()).as_ref(),
+        map_g.eval(map_f.eval(xs)).as_ref(),
+    ));
+}
+
+/// Asserts that the function `map` is a [functor].
+///
+/// It must hold:
+/// - `map` preserves the identity ([`identity`])
+/// - `map` preserves the composition of `f` and `g` ([`composition`])
+///
+/// [functor]: https://en.wikipedia.org/wiki/Functor_(functional_programming)
+pub fn functor<C, A, M, F, G>(vars: Vars<C, 1>, map: Fun2<M>, f: Fun1<F>, g: Fun1<G>)
+where
+    C: Debug + Clone + PartialEq,
+    M: Fn(C, &dyn Fn(A) -> A) -> C,
+    F: Fn(A) -> A,
+    G: Fn(A) -> A,
+{
+    hint_section!("Is `{}` a functor on `{}`?", map.name, vars.set);
+
+    for law in functor_laws(vars, map, f, g) {
+        law.assert();
+    }
+}
+
+/// Returns the laws of a [functor] (see [`functor`]).
+///
+/// [functor]: https://en.wikipedia.org/wiki/Functor_(functional_programming)
+pub fn functor_laws<'a, C, A, M, F, G>(
+    vars: Vars<'a, C, 1>,
+    map: Fun2<'a, M>,
+    f: Fun1<'a, F>,
+    g: Fun1<'a, G>,
+) -> Vec<Law<'a>>
+where
+    C: Debug + Clone + PartialEq + 'a,
+    A: 'a,
+    M: Fn(C, &dyn Fn(A) -> A) -> C + 'a,
+    F: Fn(A) -> A + 'a,
+    G: Fn(A) -> A + 'a,
+{
+    let map = ("map", &Rc::new(map));
+
+    vec![
+        identity_law(vars.clone(), map),
+        composition_law(vars, map, ("f", f), ("g", g)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn identity_law<'a, C, A, M>(
+    vars: Vars<'a, C, 1>,
+    (map_id, map): (&str, &Rc<Fun2<'a, M>>),
+) -> Option<Law<'a>>
+where
+    C: Debug + Clone + PartialEq + 'a,
+    A: 'a,
+    M: Fn(C, &dyn Fn(A) -> A) -> C + 'a,
+{
+    law::build(format!("{}_preserves_identity", map_id), |id| {
+        let [xs] = law::var_names(&vars);
+        let name = identity_name(map.name);
+        let statement = law::eq(format!("{}(id)({})", map.name, xs), xs);
+        let map = Rc::clone(map);
+
+        Law::new(id, name, statement, 1, move || {
+            identity(vars.clone(), Fun2::as_ref(&map))
+        })
+    })
+}
+
+fn composition_law<'a, C, A, M, F, G>(
+    vars: Vars<'a, C, 1>,
+    (map_id, map): (&str, &Rc<Fun2<'a, M>>),
+    (_, f): (&str, Fun1<'a, F>),
+    (_, g): (&str, Fun1<'a, G>),
+) -> Option<Law<'a>>
+where
+    C: Debug + Clone + PartialEq + 'a,
+    A: 'a,
+    M: Fn(C, &dyn Fn(A) -> A) -> C + 'a,
+    F: Fn(A) -> A + 'a,
+    G: Fn(A) -> A + 'a,
+{
+    law::build(format!("{}_preserves_composition", map_id), |id| {
+        let [xs] = law::var_names(&vars);
+        let name = composition_name(map.name, f.name, g.name);
+        let statement = law::eq(
+            format!("{}({} ∘ {})({})", map.name, g.name, f.name, xs),
+            format!("{m}({})({m}({})({}))", g.name, f.name, xs, m = map.name),
+        );
+        let map = Rc::clone(map);
+
+        Law::new(id, name, statement, 1, move || {
+            composition(vars.clone(), Fun2::as_ref(&map), f.as_ref(), g.as_ref())
+        })
+    })
+}
+
+fn identity_name(map: &str) -> String {
+    format!("Does `{}` preserve the identity?", map)
+}
+
+fn composition_name(map: &str, f: &str, g: &str) -> String {
+    format!(
+        "Does `{}` preserve the composition of `{}` and `{}`?",
+        map, g, f
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{check, props, Elem, Fun1, Fun2, Set, Vars};
+
+    #[test]
+    fn functor_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..));
+            let vars = fate.roll(set.vars(["xs"]));
+            let map = Fun2::new("map", |xs: Vec<u8>, h: &dyn Fn(u8) -> u8| {
+                xs.into_iter().map(h).collect::<Vec<_>>()
+            });
+            let f = Fun1::new("inc", |x: u8| x.wrapping_add(1));
+            let g = Fun1::new("triple", |x: u8| x.wrapping_mul(3));
+            props::functor::functor(vars, map, f, g);
+        })
+    }
+
+    #[test]
+    fn functor_laws_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Option<u8>", dice::option(dice::u8(..)));
+            let vars = fate.roll(set.vars(["xs"]));
+            let map = Fun2::new("map", |xs: Option<u8>, h: &dyn Fn(u8) -> u8| xs.map(h));
+            let f = Fun1::new("inc", |x: u8| x.wrapping_add(1));
+            let g = Fun1::new("triple", |x: u8| x.wrapping_mul(3));
+            let laws = props::functor::functor_laws(vars, map, f, g);
+
+            assert_eq!(laws[0].statement, "(map(id)(xs) == xs)");
+            assert_eq!(
+                laws[1].statement,
+                "(map(triple ∘ inc)(xs) == map(triple)(map(inc)(xs)))"
+            );
+
+            for law in laws {
+                law.assert();
+            }
+        })
+    }
+
+    #[test]
+    fn identity_detects_reordering() {
+        let vars = Vars::new("Vec<u8>", [Elem::new("xs", vec![1, 2])]);
+        let map = Fun2::new("map", |xs: Vec<u8>, h: &dyn Fn(u8) -> u8| {
+            xs.into_iter().rev().map(h).collect::<Vec<_>>()
+        });
+
+        let failure = check(|| props::functor::identity(vars, map)).unwrap_err();
+
+        assert_eq!(failure.assertion, "(map(id)(xs) == xs)");
+    }
+}
@@ -0,0 +1,222 @@
+//! Properties for the arithmetic of [fixed-width integers].
+//!
+//! The properties compare the checked, overflowing, wrapping and saturating variants of an
+//! operation with each other or with the same operation on a wider reference type. The
+//! functions `widen` and `narrow` convert between both types, `narrow` must truncate its
+//! argument modulo 2ⁿ (e.g. like `as` casts do).
+//!
+//! That the wrapping operations form a ring modulo 2ⁿ can be asserted with
+//! [`algebra::commutative_ring`].
+//!
+//! [fixed-width integers]: https://en.wikipedia.org/wiki/Integer_(computer_science)
+//! [`algebra::commutative_ring`]: crate::props::algebra::commutative_ring
+
+use dicetest::hint_section;
+use std::fmt::Debug;
+
+use crate::{ops, Elem, Fun1, Fun2, Fun3, Vars};
+
+/// Asserts that the checked operation `checked` and the overflowing operation `overflowing`
+/// are consistent.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `is_none(checked(a, b)) <-> overflowing(a, b).1`
+/// - if `checked(a, b)` returns `Some(c)`, then `c == overflowing(a, b).0`
+pub fn checked_overflowing<S, C, O>(vars: Vars<S, 2>, checked: Fun2<C>, overflowing: Fun2<O>)
+where
+    S: Debug + Clone + PartialEq,
+    C: FnOnce(S, S) -> Option<S>,
+    O: FnOnce(S, S) -> (S, bool),
+{
+    hint_section!(
+        "Are `{}` and `{}` consistent?",
+        checked.name,
+        overflowing.name,
+    );
+
+    let [a, b] = vars.eval();
+    let is_none = Fun1::new("is_none", Option::is_none);
+    let result = Fun1::postfix(".0", |(result, _): (S, bool)| result);
+    let overflow = Fun1::postfix(".1", |(_, overflow): (S, bool)| overflow);
+    let unwrap = Fun1::postfix(".unwrap()", Option::unwrap);
+
+    let c = checked.eval_once(a.clone(), b.clone());
+    let d = overflowing.eval_once(a, b);
+
+    ops::assert(ops::iff(is_none.eval(c.as_ref()), overflow.eval(d.clone())));
+
+    if c.value.is_some() {
+        ops::assert(ops::eq(unwrap.eval(c).as_ref(), result.eval(d).as_ref()));
+    }
+}
+
+/// Asserts that the wrapping operation `wrapping` agrees with the operation `op` of a wider
+/// type modulo 2ⁿ.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `wrapping(a, b) == narrow(op(widen(a), widen(b)))`
+pub fn wrapping<S, W, F, N, O, P>(
+    vars: Vars<S, 2>,
+    widen: Fun1<F>,
+    narrow: Fun1<N>,
+    wrapping: Fun2<O>,
+    op: Fun2<P>,
+) where
+    S: Debug + Clone + PartialEq,
+    W: Debug,
+    F: Fn(S) -> W,
+    N: FnOnce(W) -> S,
+    O: FnOnce(S, S) -> S,
+    P: FnOnce(W, W) -> W,
+{
+    hint_section!(
+        "Is `{}` equal to `{}` after `{}` and `{}`?",
+        wrapping.name,
+        op.name,
+        widen.name,
+        narrow.name,
+    );
+
+    let [a, b] = vars.eval();
+
+    let lhs = wrapping.eval_once(a.clone(), b.clone());
+    let rhs = narrow.eval_once(op.eval_once(widen.eval(a), widen.eval(b)));
+
+    ops::assert(ops::eq(lhs.as_ref(), rhs.as_ref()));
+}
+
+/// Asserts that the saturating operation `saturating` agrees with the operation `op` of a
+/// wider type clamped to the bounds `min` and `max`.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `widen(saturating(a, b)) == clamp(op(widen(a), widen(b)), widen(min), widen(max))`
+pub fn saturating<S, W, F, O, P>(
+    vars: Vars<S, 2>,
+    widen: Fun1<F>,
+    saturating: Fun2<O>,
+    op: Fun2<P>,
+    min: Elem<S>,
+    max: Elem<S>,
+) where
+    S: Debug + Clone,
+    W: Debug + Ord,
+    F: Fn(S) -> W,
+    O: FnOnce(S, S) -> S,
+    P: FnOnce(W, W) -> W,
+{
+    hint_section!(
+        "Is `{}` equal to `{}` clamped to `{}` and `{}`?",
+        saturating.name,
+        op.name,
+        min.name,
+        max.name,
+    );
+
+    let [a, b] = vars.eval();
+    let min = min.eval();
+    let max = max.eval();
+    let clamp = Fun3::new("clamp", W::clamp);
+
+    let lhs = widen.eval(saturating.eval_once(a.clone(), b.clone()));
+    let rhs = clamp.eval_once(
+        op.eval_once(widen.eval(a), widen.eval(b)),
+        widen.eval(min),
+        widen.eval(max),
+    );
+
+    ops::assert(ops::eq(lhs.as_ref(), rhs.as_ref()));
+}
+
+/// Asserts that the saturating operation `saturating` is [monotone] in its first argument.
+///
+/// For all `a`, `b`, `c` of `vars.set` it must hold:
+/// - `(a <= b) --> (saturating(a, c) <= saturating(b, c))`
+///
+/// This holds e.g. for `saturating_add` and `saturating_sub`, but for `saturating_mul` only if
+/// `c` is non-negative.
+///
+/// [monotone]: https://en.wikipedia.org/wiki/Monotonic_function
+pub fn saturating_monotone<S, O>(vars: Vars<S, 3>, saturating: Fun2<O>)
+where
+    S: Debug + Clone + PartialOrd,
+    O: Fn(S, S) -> S,
+{
+    hint_section!("Is `{}` monotone?", saturating.name);
+
+    let [a, b, c] = vars.eval();
+
+    let lhs = saturating.eval(a.clone(), c.clone());
+    let rhs = saturating.eval(b.clone(), c);
+
+    ops::assert(ops::implies(
+        ops::le(a.as_ref(), b.as_ref()),
+        ops::le(lhs.as_ref(), rhs.as_ref()),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+    use std::num::Wrapping;
+
+    use crate::{props, Elem, Fun1, Fun2, Set};
+
+    #[test]
+    fn checked_overflowing_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i8", dice::i8(..));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let checked = Fun2::new("checked_add", i8::checked_add);
+            let overflowing = Fun2::new("overflowing_add", i8::overflowing_add);
+            props::int::checked_overflowing(vars, checked, overflowing);
+        })
+    }
+
+    #[test]
+    fn wrapping_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i8", dice::i8(..));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let widen = Fun1::new("i32::from", i32::from);
+            let narrow = Fun1::postfix(" as i8", |x: i32| x as i8);
+            let wrapping = Fun2::new("wrapping_mul", i8::wrapping_mul);
+            let op = Fun2::infix("*", |x, y| x * y);
+            props::int::wrapping(vars, widen, narrow, wrapping, op);
+        })
+    }
+
+    #[test]
+    fn wrapping_ring_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("Wrapping<u8>", dice::u8(..).map(Wrapping));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let zero = Elem::new("zero", Wrapping(0));
+            let one = Elem::new("one", Wrapping(1));
+            props::algebra::commutative_ring_of(vars, zero, one);
+        })
+    }
+
+    #[test]
+    fn saturating_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("u8", dice::u8(..));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let widen = Fun1::new("u16::from", u16::from);
+            let saturating = Fun2::new("saturating_add", u8::saturating_add);
+            let op = Fun2::infix("+", |x, y| x + y);
+            let min = Elem::new("min", u8::MIN);
+            let max = Elem::new("max", u8::MAX);
+            props::int::saturating(vars, widen, saturating, op, min, max);
+        })
+    }
+
+    #[test]
+    fn saturating_monotone_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i8", dice::i8(..));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let saturating = Fun2::new("saturating_sub", i8::saturating_sub);
+            props::int::saturating_monotone(vars, saturating);
+        })
+    }
+}
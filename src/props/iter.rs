@@ -0,0 +1,369 @@
+//! Properties for implementations of [`Iterator`] and its related traits.
+//!
+//! The iterators are built by a [`Fun1`] `iter` from the elements of `vars.set`, hence every
+//! property can create as many fresh iterators as it needs. The labels describe the evaluated
+//! iterator methods as functions, e.g. `count(iter(s))` for `iter(s).count()`.
+//!
+//! The methods [`Iterator::nth`], [`Iterator::skip`] and [`Iterator::last`] are often
+//! overridden for performance reasons. The properties compare them with implementations that
+//! use only [`Iterator::next`].
+
+use dicetest::hint_section;
+use std::fmt::Debug;
+use std::iter::FusedIterator;
+
+use crate::{ops, Eval, Fun1, Fun2, Vars};
+
+/// Asserts that [`Iterator::size_hint`] returns bounds of the actual number of elements.
+///
+/// For all `s` of `vars.set` it must hold:
+/// - `lower_bound(iter(s)) <= count(iter(s))`
+/// - if `upper_bound(iter(s))` returns `Some(n)`, then `count(iter(s)) <= n`
+pub fn size_hint<S, F, I>(vars: Vars<S, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: Iterator,
+{
+    hint_section!("Does `{}` have a valid `size_hint`?", iter.name);
+
+    let [s] = vars.eval();
+    let iter_label = iter.label(s.label).to_string();
+    let it = Eval::labelled(&iter_label, s.value);
+    let count = Fun1::new("count", |s| (iter.f())(s).count());
+    let lower_bound = Fun1::new("lower_bound", |s| (iter.f())(s).size_hint().0);
+    let upper_bound = Fun1::new("upper_bound", |s| (iter.f())(s).size_hint().1);
+    let unwrap = Fun1::postfix(".unwrap()", Option::unwrap);
+
+    let n = count.eval(it.clone());
+    let upper = upper_bound.eval(it.clone());
+
+    ops::assert(ops::le(lower_bound.eval(it).as_ref(), n.as_ref()));
+
+    if upper.value.is_some() {
+        ops::assert(ops::le(n.as_ref(), unwrap.eval(upper).as_ref()));
+    }
+}
+
+/// Asserts that [`ExactSizeIterator::len`] returns the actual number of elements.
+///
+/// For all `s` of `vars.set` it must hold:
+/// - `len(iter(s)) == count(iter(s))`
+pub fn len<S, F, I>(vars: Vars<S, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: ExactSizeIterator,
+{
+    hint_section!("Does `{}` have a valid `len`?", iter.name);
+
+    let [s] = vars.eval();
+    let iter_label = iter.label(s.label).to_string();
+    let it = Eval::labelled(&iter_label, s.value);
+    let len = Fun1::new("len", |s| (iter.f())(s).len());
+    let count = Fun1::new("count", |s| (iter.f())(s).count());
+
+    ops::assert(ops::eq(
+        len.eval(it.clone()).as_ref(),
+        count.eval(it).as_ref(),
+    ));
+}
+
+/// Asserts that [`DoubleEndedIterator::next_back`] returns the same elements as
+/// [`Iterator::next`] in reversed order.
+///
+/// For all `s` of `vars.set` it must hold:
+/// - `collect(rev(iter(s))) == reverse(collect(iter(s)))`
+pub fn rev<S, F, I>(vars: Vars<S, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: DoubleEndedIterator,
+    I::Item: Debug + PartialEq,
+{
+    hint_section!("Is `{}` consistent in both directions?", iter.name);
+
+    let [s] = vars.eval();
+    let iter_label = iter.label(s.label).to_string();
+    let it = Eval::labelled(&iter_label, s.value);
+    let collect = Fun1::new("collect", |s| (iter.f())(s).collect::<Vec<_>>());
+    let collect_rev = Fun1::new("collect", |s| (iter.f())(s).rev().collect::<Vec<_>>());
+    let rev_label = format!("rev({})", it.label);
+    let rev = Eval::labelled(&rev_label, it.value.clone());
+    let reverse = Fun1::new("reverse", |mut xs: Vec<I::Item>| {
+        xs.reverse();
+        xs
+    });
+
+    ops::assert(ops::eq(
+        collect_rev.eval(rev).as_ref(),
+        reverse.eval(collect.eval(it)).as_ref(),
+    ));
+}
+
+/// Asserts that a [`FusedIterator`] keeps returning `None` after it returned `None` once.
+///
+/// For all `s` of `vars.set` it must hold:
+/// - `nexts_after_end(iter(s)) == [None, None, None]`
+pub fn fused<S, F, I>(vars: Vars<S, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: FusedIterator,
+    I::Item: Debug + PartialEq,
+{
+    hint_section!("Is `{}` fused?", iter.name);
+
+    let [s] = vars.eval();
+    let iter_label = iter.label(s.label).to_string();
+    let it = Eval::labelled(&iter_label, s.value);
+    let nexts_after_end = Fun1::new("nexts_after_end", |s| {
+        let mut it = (iter.f())(s);
+        it.by_ref().for_each(drop);
+        (0..3).map(|_| it.next()).collect::<Vec<_>>()
+    });
+    let nones = Eval::labelled(
+        "[None, None, None]",
+        (0..3).map(|_| None).collect::<Vec<_>>(),
+    );
+
+    ops::assert(ops::eq(nexts_after_end.eval(it).as_ref(), nones.as_ref()));
+}
+
+/// Asserts that [`Iterator::nth`] agrees with its default implementation.
+///
+/// For all `s` of `vars.set` and `n` of `n_vars.set` it must hold:
+/// - `nth(iter(s), n) == nth_by_next(iter(s), n)`
+pub fn nth<S, F, I>(vars: Vars<S, 1>, n_vars: Vars<usize, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: Iterator,
+    I::Item: Debug + PartialEq,
+{
+    hint_section!("Does `nth` of `{}` agree with `next`?", iter.name);
+
+    let [s] = vars.eval();
+    let [n] = n_vars.eval();
+    let iter_label = iter.label(s.label).to_string();
+    let it = Eval::labelled(&iter_label, s.value);
+    let nth = Fun2::new("nth", |s, n| (iter.f())(s).nth(n));
+    let nth_by_next = Fun2::new("nth_by_next", |s, n| {
+        let mut it = (iter.f())(s);
+        for _ in 0..n {
+            it.next()?;
+        }
+        it.next()
+    });
+
+    ops::assert(ops::eq(
+        nth.eval(it.clone(), n).as_ref(),
+        nth_by_next.eval(it, n).as_ref(),
+    ));
+}
+
+/// Asserts that [`Iterator::skip`] agrees with skipping elements via [`Iterator::next`].
+///
+/// For all `s` of `vars.set` and `n` of `n_vars.set` it must hold:
+/// - `skip(iter(s), n) == skip_by_next(iter(s), n)`
+///
+/// Both sides collect the remaining elements into a [`Vec`].
+pub fn skip<S, F, I>(vars: Vars<S, 1>, n_vars: Vars<usize, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: Iterator,
+    I::Item: Debug + PartialEq,
+{
+    hint_section!("Does `skip` of `{}` agree with `next`?", iter.name);
+
+    let [s] = vars.eval();
+    let [n] = n_vars.eval();
+    let iter_label = iter.label(s.label).to_string();
+    let it = Eval::labelled(&iter_label, s.value);
+    let skip = Fun2::new("skip", |s, n| (iter.f())(s).skip(n).collect::<Vec<_>>());
+    let skip_by_next = Fun2::new("skip_by_next", |s, n| {
+        let mut it = (iter.f())(s);
+        for _ in 0..n {
+            if it.next().is_none() {
+                break;
+            }
+        }
+        it.collect::<Vec<_>>()
+    });
+
+    ops::assert(ops::eq(
+        skip.eval(it.clone(), n).as_ref(),
+        skip_by_next.eval(it, n).as_ref(),
+    ));
+}
+
+/// Asserts that [`Iterator::last`] agrees with its default implementation.
+///
+/// For all `s` of `vars.set` it must hold:
+/// - `last(iter(s)) == last_by_next(iter(s))`
+pub fn last<S, F, I>(vars: Vars<S, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: Iterator,
+    I::Item: Debug + PartialEq,
+{
+    hint_section!("Does `last` of `{}` agree with `next`?", iter.name);
+
+    let [s] = vars.eval();
+    let iter_label = iter.label(s.label).to_string();
+    let it = Eval::labelled(&iter_label, s.value);
+    let last = Fun1::new("last", |s| (iter.f())(s).last());
+    let last_by_next = Fun1::new("last_by_next", |s| {
+        let mut last = None;
+        for x in (iter.f())(s) {
+            last = Some(x);
+        }
+        last
+    });
+
+    ops::assert(ops::eq(
+        last.eval(it.clone()).as_ref(),
+        last_by_next.eval(it).as_ref(),
+    ));
+}
+
+/// Asserts that the iterators built by `iter` are valid [`Iterator`]s.
+///
+/// It must hold:
+/// - `size_hint` returns valid bounds ([`size_hint`])
+/// - `nth` agrees with its default implementation ([`nth`])
+/// - `skip` agrees with skipping via `next` ([`skip`])
+/// - `last` agrees with its default implementation ([`last`])
+///
+/// The properties [`len`], [`rev`] and [`fused`] are not included because they require the
+/// traits [`ExactSizeIterator`], [`DoubleEndedIterator`] and [`FusedIterator`]. Assert them
+/// separately if `iter` builds iterators that implement these traits.
+pub fn iterator<S, F, I>(vars: Vars<S, 1>, n_vars: Vars<usize, 1>, iter: Fun1<F>)
+where
+    S: Debug + Clone,
+    F: Fn(S) -> I,
+    I: Iterator,
+    I::Item: Debug + PartialEq,
+{
+    hint_section!("Is `{}` a valid iterator on `{}`?", iter.name, vars.set);
+
+    size_hint(vars.clone(), iter.as_ref());
+    nth(vars.clone(), n_vars, iter.as_ref());
+    skip(vars.clone(), n_vars, iter.as_ref());
+    last(vars, iter);
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{check, props, Elem, Fun1, Set, Vars};
+
+    #[test]
+    fn iterator_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..));
+            let n_set = Set::new("usize ∩ [0,20]", dice::usize(..=20));
+            let vars = fate.roll(set.vars(["xs"]));
+            let n_vars = fate.roll(n_set.vars(["n"]));
+            let iter = Fun1::new("chunks", |xs: Vec<u8>| {
+                xs.chunks(3)
+                    .map(<[u8]>::to_vec)
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            });
+            props::iter::iterator(vars, n_vars, iter);
+        })
+    }
+
+    #[test]
+    fn len_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..));
+            let vars = fate.roll(set.vars(["xs"]));
+            let iter = Fun1::new("into_iter", Vec::into_iter);
+            props::iter::len(vars, iter);
+        })
+    }
+
+    #[test]
+    fn rev_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("String", dice::string(dice::char(), ..));
+            let vars = fate.roll(set.vars(["s"]));
+            let iter = Fun1::new("chars", |s: String| {
+                s.chars().collect::<Vec<_>>().into_iter()
+            });
+            props::iter::rev(vars, iter);
+        })
+    }
+
+    #[test]
+    fn fused_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..));
+            let vars = fate.roll(set.vars(["xs"]));
+            let iter = Fun1::new("into_iter", Vec::into_iter);
+            props::iter::fused(vars, iter);
+        })
+    }
+
+    #[test]
+    fn size_hint_detects_lie() {
+        let vars = Vars::new("Vec<u8>", [Elem::new("xs", vec![1, 2])]);
+        let iter = Fun1::new("lying_iter", |xs: Vec<u8>| LyingIter(xs.into_iter()));
+
+        let failure = check(|| props::iter::size_hint(vars, iter)).unwrap_err();
+
+        assert_eq!(
+            failure.assertion,
+            "(count(lying_iter(xs)) <= (upper_bound(lying_iter(xs))).unwrap())"
+        );
+    }
+
+    struct LyingIter(std::vec::IntoIter<u8>);
+
+    impl Iterator for LyingIter {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, Some(0))
+        }
+    }
+
+    #[test]
+    fn skip_detects_wrong_nth() {
+        let vars = Vars::new("Vec<u8>", [Elem::new("xs", vec![1, 2, 3])]);
+        let n_vars = Vars::new("usize", [Elem::new("n", 1)]);
+        let iter = Fun1::new("off_by_one_iter", |xs: Vec<u8>| {
+            OffByOneIter(xs.into_iter())
+        });
+
+        let failure = check(|| props::iter::skip(vars, n_vars, iter)).unwrap_err();
+
+        assert_eq!(
+            failure.assertion,
+            "(skip(off_by_one_iter(xs), n) == skip_by_next(off_by_one_iter(xs), n))"
+        );
+    }
+
+    struct OffByOneIter(std::vec::IntoIter<u8>);
+
+    impl Iterator for OffByOneIter {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            self.0.next()
+        }
+
+        fn nth(&mut self, n: usize) -> Option<u8> {
+            self.0.nth(n + 1)
+        }
+    }
+}
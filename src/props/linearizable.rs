@@ -0,0 +1,367 @@
+//! Properties for [linearizability], i.e. comparing a concurrent system under test with a
+//! sequential model.
+//!
+//! The operations are applied from several threads and the recorded history is searched for a
+//! sequential order that is consistent with the model. For sequential systems under test see
+//! [`model`](crate::props::model).
+//!
+//! [linearizability]: https://en.wikipedia.org/wiki/Linearizability
+
+use dicetest::{hint, hint_section};
+use std::fmt::Debug;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::check::CheckModeScope;
+use crate::{ops, Elem, Eval, Fun2, Vars};
+
+/// Asserts that the concurrent system under test `sut` is [linearizable] regarding to the
+/// sequential model `model`.
+///
+/// Each element of `ops_per_thread` contains the operations of one thread. The threads apply
+/// their operations concurrently to `sut` via `apply` and record the invocation and response
+/// times. Afterwards the history is searched for a sequential order of the operations that
+/// respects the real-time order and that produces the same results when applied to `model`
+/// via `step` (see [Wing and Gong]). The history is logged as hint section.
+///
+/// The search is exponential in the number of concurrent operations, hence the number of
+/// operations per test should be small.
+///
+/// `apply` runs on spawned threads. Assertions in `apply` (e.g. via [`ops::assert`]) fail the
+/// property like assertions on the calling thread, also inside of [`check`]. Hints logged by
+/// `apply` are not recorded.
+///
+/// For all `ops_per_thread` of `vars.set` it must hold:
+/// - the history of `apply(sut, op)` has a linearization regarding to `step`
+///
+/// [linearizable]: https://en.wikipedia.org/wiki/Linearizability
+/// [`check`]: crate::check
+/// [Wing and Gong]: https://doi.org/10.1006/jpdc.1993.1015
+pub fn linearizable<T, O, R, A, M, S>(
+    vars: Vars<Vec<Vec<O>>, 1>,
+    sut: Elem<T>,
+    apply: Fun2<A>,
+    model: Elem<M>,
+    step: Fun2<S>,
+) where
+    T: Debug + Sync,
+    O: Debug + Clone + Send,
+    R: Debug + PartialEq + Send,
+    A: Fn(&T, O) -> R + Sync,
+    M: Debug + Clone,
+    S: Fn(M, O) -> (M, R),
+{
+    hint_section!(
+        "Is `{}` of `{}` linearizable regarding to `{}` of `{}`?",
+        apply.name,
+        sut.name,
+        step.name,
+        model.name,
+    );
+
+    let [ops_per_thread] = vars.eval();
+    let sut = sut.eval();
+    let model = model.eval();
+
+    let history = record_history(&sut.value, ops_per_thread.value, apply.f());
+
+    {
+        hint_section!("History:");
+
+        for (thread, events) in history.iter().enumerate() {
+            for event in events {
+                hint!(
+                    "thread {}: {}({:?}) = {:?} during [{}, {}]",
+                    thread,
+                    apply.name,
+                    event.op,
+                    event.result,
+                    event.invoked,
+                    event.returned,
+                );
+            }
+        }
+    }
+
+    let linearization = linearize(&history, model.value, step.f());
+
+    if let Some(linearization) = &linearization {
+        let ops = linearization
+            .iter()
+            .map(|&(thread, i)| &history[thread][i].op)
+            .collect::<Vec<_>>();
+        hint!("linearization = {:?}", ops);
+    }
+
+    ops::assert(Eval::labelled(
+        "is_linearizable(history)",
+        linearization.is_some(),
+    ));
+}
+
+/// An operation of a concurrent history with its result and its invocation and response times.
+struct Event<O, R> {
+    op: O,
+    result: R,
+    invoked: usize,
+    returned: usize,
+}
+
+fn record_history<T, O, R, A>(
+    sut: &T,
+    ops_per_thread: Vec<Vec<O>>,
+    apply: &A,
+) -> Vec<Vec<Event<O, R>>>
+where
+    T: Sync,
+    O: Clone + Send,
+    R: Send,
+    A: Fn(&T, O) -> R + Sync,
+{
+    let clock = AtomicUsize::new(0);
+    let check_mode = CheckModeScope::current();
+
+    thread::scope(|scope| {
+        let handles = ops_per_thread
+            .into_iter()
+            .map(|ops| {
+                let clock = &clock;
+                scope.spawn(move || {
+                    check_mode.run(|| {
+                        ops.into_iter()
+                            .map(|op| {
+                                let invoked = clock.fetch_add(1, Ordering::SeqCst);
+                                let result = apply(sut, op.clone());
+                                let returned = clock.fetch_add(1, Ordering::SeqCst);
+                                Event {
+                                    op,
+                                    result,
+                                    invoked,
+                                    returned,
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|err| panic::resume_unwind(err))
+            })
+            .collect()
+    })
+}
+
+/// Returns the indices of the events in linearized order or `None` if there is none.
+fn linearize<O, R, M, S>(
+    history: &[Vec<Event<O, R>>],
+    model: M,
+    step: &S,
+) -> Option<Vec<(usize, usize)>>
+where
+    O: Clone,
+    R: PartialEq,
+    M: Clone,
+    S: Fn(M, O) -> (M, R),
+{
+    fn search<O, R, M, S>(
+        history: &[Vec<Event<O, R>>],
+        next_events: &mut Vec<usize>,
+        model: M,
+        step: &S,
+        linearization: &mut Vec<(usize, usize)>,
+    ) -> bool
+    where
+        O: Clone,
+        R: PartialEq,
+        M: Clone,
+        S: Fn(M, O) -> (M, R),
+    {
+        // The events of a thread are sequential, hence only the first pending event of each
+        // thread can be the next one and the earliest response is among them
+        let pending: Vec<Option<&Event<O, R>>> = (0..history.len())
+            .map(|thread| history[thread].get(next_events[thread]))
+            .collect();
+        let first_returned = match pending.iter().flatten().map(|e| e.returned).min() {
+            Some(first_returned) => first_returned,
+            None => return true,
+        };
+
+        for (thread, event) in pending.into_iter().enumerate() {
+            let event = match event {
+                Some(event) if event.invoked < first_returned => event,
+                _ => continue,
+            };
+
+            let (next_model, result) = step(model.clone(), event.op.clone());
+
+            if result == event.result {
+                linearization.push((thread, next_events[thread]));
+                next_events[thread] += 1;
+
+                if search(history, next_events, next_model, step, linearization) {
+                    return true;
+                }
+
+                next_events[thread] -= 1;
+                linearization.pop();
+            }
+        }
+
+        false
+    }
+
+    let mut next_events = vec![0; history.len()];
+    let mut linearization = Vec::new();
+
+    if search(history, &mut next_events, model, step, &mut linearization) {
+        Some(linearization)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+    use std::collections::VecDeque;
+    use std::sync::atomic::{AtomicU8, Ordering};
+    use std::sync::Mutex;
+
+    use crate::props::linearizable::{self, linearize, Event};
+    use crate::{check, ops, Elem, Eval, Fun2, Set, Vars};
+
+    #[derive(Debug, Clone)]
+    enum QueueOp {
+        Push(u8),
+        Pop,
+    }
+
+    fn queue_step(mut queue: VecDeque<u8>, op: QueueOp) -> (VecDeque<u8>, Option<u8>) {
+        match op {
+            QueueOp::Push(x) => {
+                queue.push_back(x);
+                (queue, None)
+            }
+            QueueOp::Pop => {
+                let x = queue.pop_front();
+                (queue, x)
+            }
+        }
+    }
+
+    #[test]
+    fn linearizable_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let op_die =
+                dice::one_of_die().two(dice::u8(..).map(QueueOp::Push), dice::just(QueueOp::Pop));
+            let set = Set::new(
+                "Vec<Vec<QueueOp>>",
+                dice::vec(dice::vec(op_die, ..4), 1..=3),
+            );
+            let vars = fate.roll(set.vars(["ops_per_thread"]));
+            let sut = Elem::new("mutex_queue", Mutex::new(VecDeque::new()));
+            let apply = Fun2::new("apply", |queue: &Mutex<VecDeque<u8>>, op| {
+                let mut queue = queue.lock().unwrap();
+                match op {
+                    QueueOp::Push(x) => {
+                        queue.push_back(x);
+                        None
+                    }
+                    QueueOp::Pop => queue.pop_front(),
+                }
+            });
+            let model = Elem::new("queue", VecDeque::new());
+            let step = Fun2::new("step", queue_step);
+            linearizable::linearizable(vars, sut, apply, model, step);
+        })
+    }
+
+    #[test]
+    fn linearizable_detects_wrong_result() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<Vec<()>>", dice::just(vec![vec![(), ()]]));
+            let vars = fate.roll(set.vars(["ops_per_thread"]));
+            let sut = Elem::new("counter", AtomicU8::new(0));
+            let apply = Fun2::new("fetch_inc", |counter: &AtomicU8, ()| {
+                counter.load(Ordering::SeqCst)
+            });
+            let model = Elem::new("count", 0);
+            let step = Fun2::new("inc", |count: u8, ()| (count + 1, count));
+
+            let failure =
+                check(|| linearizable::linearizable(vars, sut, apply, model, step)).unwrap_err();
+
+            assert_eq!(failure.assertion, "is_linearizable(history)");
+        })
+    }
+
+    #[test]
+    fn linearize_reorders_overlapping_events() {
+        let history = vec![
+            vec![Event {
+                op: QueueOp::Push(1),
+                result: None,
+                invoked: 0,
+                returned: 3,
+            }],
+            vec![Event {
+                op: QueueOp::Pop,
+                result: Some(1),
+                invoked: 1,
+                returned: 2,
+            }],
+        ];
+
+        let linearization = linearize(&history, VecDeque::new(), &queue_step);
+
+        assert_eq!(linearization, Some(vec![(0, 0), (1, 0)]));
+    }
+
+    #[test]
+    fn linearize_respects_real_time_order() {
+        let history = vec![
+            vec![Event {
+                op: QueueOp::Push(1),
+                result: None,
+                invoked: 2,
+                returned: 3,
+            }],
+            vec![Event {
+                op: QueueOp::Pop,
+                result: Some(1),
+                invoked: 0,
+                returned: 1,
+            }],
+        ];
+
+        let linearization = linearize(&history, VecDeque::new(), &queue_step);
+
+        assert_eq!(linearization, None);
+    }
+
+    #[test]
+    fn linearizable_fails_on_assertion_of_apply() {
+        let vars = Vars::new(
+            "Vec<Vec<u8>>",
+            [Elem::new("ops_per_thread", vec![vec![1], vec![2]])],
+        );
+        let sut = Elem::new("unit", ());
+        let apply = Fun2::new("apply", |_: &(), x: u8| {
+            ops::assert(Eval::labelled("is_one(x)", x == 1));
+        });
+        let model = Elem::new("unit", ());
+        let step = Fun2::new("step", |(), _: u8| ((), ()));
+
+        let failure =
+            check(|| linearizable::linearizable(vars, sut, apply, model, step)).unwrap_err();
+
+        assert_eq!(failure.assertion, "is_one(x)");
+    }
+}
@@ -0,0 +1,576 @@
+//! Properties for [metrics] and their generalizations.
+//!
+//! The distance function `dist` maps two elements of `vars.set` to a distance of type `D`.
+//! The distances are ordered by [`PartialOrd`] and can be added with the function `add`.
+//!
+//! [metrics]: https://en.wikipedia.org/wiki/Metric_space
+
+use dicetest::hint_section;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::law::{self, Law};
+use crate::props::fun;
+use crate::{ops, Elem, Fun2, Vars};
+
+/// Asserts that the distance function `dist` is non-negative.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `zero <= dist(a, b)`
+pub fn non_negative<S, D, F>(vars: Vars<S, 2>, dist: Fun2<F>, zero: Elem<D>)
+where
+    S: Debug + Clone,
+    D: Debug + PartialOrd,
+    F: FnOnce(S, S) -> D,
+{
+    hint_section!("{}", non_negative_name(dist.name));
+
+    let [a, b] = vars.eval();
+    let zero = zero.eval();
+
+    ops::assert(ops::le(zero.as_ref(), dist.eval_once(a, b).as_ref()));
+}
+
+/// Asserts that the distance of each element to itself is `zero`.
+///
+/// For all `a` of `vars.set` it must hold:
+/// - `dist(a, a) == zero`
+pub fn zero_self_distance<S, D, F>(vars: Vars<S, 1>, dist: Fun2<F>, zero: Elem<D>)
+where
+    S: Debug + Clone,
+    D: Debug + PartialEq,
+    F: FnOnce(S, S) -> D,
+{
+    hint_section!("{}", zero_self_distance_name(dist.name));
+
+    let [a] = vars.eval();
+    let zero = zero.eval();
+
+    ops::assert(ops::eq(
+        dist.eval_once(a.clone(), a).as_ref(),
+        zero.as_ref(),
+    ));
+}
+
+/// Asserts that the distance function `dist` fulfills the [identity of indiscernibles].
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `dist(a, a) == zero`
+/// - `(dist(a, b) == zero) <-> (a == b)`
+///
+/// [identity of indiscernibles]: https://en.wikipedia.org/wiki/Metric_space#Definition
+pub fn identity_of_indiscernibles<S, D, F>(vars: Vars<S, 2>, dist: Fun2<F>, zero: Elem<D>)
+where
+    S: Debug + Clone + PartialEq,
+    D: Debug + Clone + PartialEq,
+    F: Fn(S, S) -> D,
+{
+    hint_section!("{}", identity_of_indiscernibles_name(dist.name));
+
+    let [a, b] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_2 = Vars::new(vars.set, [a, b]);
+
+    zero_self_distance(vars_1, dist.as_ref(), zero.clone());
+
+    let [a, b] = vars_2.eval();
+    let zero = zero.eval();
+
+    ops::assert(ops::iff(
+        ops::eq(dist.eval(a.clone(), b.clone()).as_ref(), zero.as_ref()),
+        ops::eq(a.as_ref(), b.as_ref()),
+    ));
+}
+
+/// Asserts that the distance function `dist` fulfills the [triangle inequality].
+///
+/// For all `a`, `b`, `c` of `vars.set` it must hold:
+/// - `dist(a, c) <= add(dist(a, b), dist(b, c))`
+///
+/// [triangle inequality]: https://en.wikipedia.org/wiki/Triangle_inequality
+pub fn triangle_inequality<S, D, F, A>(vars: Vars<S, 3>, dist: Fun2<F>, add: Fun2<A>)
+where
+    S: Debug + Clone,
+    D: Debug + PartialOrd,
+    F: Fn(S, S) -> D,
+    A: Fn(D, D) -> D,
+{
+    hint_section!("{}", triangle_inequality_name(dist.name, add.name));
+
+    let [a, b, c] = vars.eval();
+
+    let direct = dist.eval(a.clone(), c.clone());
+    let detour = add.eval_once(dist.eval(a, b.clone()), dist.eval(b, c));
+
+    ops::assert(ops::le(direct.as_ref(), detour.as_ref()));
+}
+
+/// Asserts that the distance function `dist` fulfills the strong triangle inequality of an
+/// [ultrametric].
+///
+/// For all `a`, `b`, `c` of `vars.set` it must hold:
+/// - `dist(a, c) <= max(dist(a, b), dist(b, c))`
+///
+/// [ultrametric]: https://en.wikipedia.org/wiki/Ultrametric_space
+pub fn ultrametric_inequality<S, D, F>(vars: Vars<S, 3>, dist: Fun2<F>)
+where
+    S: Debug + Clone,
+    D: Debug + PartialOrd,
+    F: Fn(S, S) -> D,
+{
+    hint_section!("{}", ultrametric_inequality_name(dist.name));
+
+    let [a, b, c] = vars.eval();
+    let max = max_fun();
+
+    let direct = dist.eval(a.clone(), c.clone());
+    let detour = max.eval_once(dist.eval(a, b.clone()), dist.eval(b, c));
+
+    ops::assert(ops::le(direct.as_ref(), detour.as_ref()));
+}
+
+/// Asserts that the distance function `dist` is a [metric].
+///
+/// It must hold:
+/// - `dist` is non-negative ([`non_negative`])
+/// - `dist` fulfills the identity of indiscernibles ([`identity_of_indiscernibles`])
+/// - `dist` is symmetric ([`fun::commutative`])
+/// - `dist` fulfills the triangle inequality ([`triangle_inequality`])
+///
+/// [metric]: https://en.wikipedia.org/wiki/Metric_space
+pub fn metric<S, D, F, A>(vars: Vars<S, 3>, dist: Fun2<F>, add: Fun2<A>, zero: Elem<D>)
+where
+    S: Debug + Clone + PartialEq,
+    D: Debug + Clone + PartialOrd,
+    F: Fn(S, S) -> D,
+    A: Fn(D, D) -> D,
+{
+    hint_section!("Is `{}` a metric on `{}`?", dist.name, vars.set);
+
+    for law in metric_laws(vars, dist, add, zero) {
+        law.assert();
+    }
+}
+
+/// Asserts that the distance function `dist` is a [pseudometric].
+///
+/// It must hold:
+/// - `dist` is non-negative ([`non_negative`])
+/// - The distance of each element to itself is `zero` ([`zero_self_distance`])
+/// - `dist` is symmetric ([`fun::commutative`])
+/// - `dist` fulfills the triangle inequality ([`triangle_inequality`])
+///
+/// [pseudometric]: https://en.wikipedia.org/wiki/Pseudometric_space
+pub fn pseudometric<S, D, F, A>(vars: Vars<S, 3>, dist: Fun2<F>, add: Fun2<A>, zero: Elem<D>)
+where
+    S: Debug + Clone,
+    D: Debug + Clone + PartialOrd,
+    F: Fn(S, S) -> D,
+    A: Fn(D, D) -> D,
+{
+    hint_section!("Is `{}` a pseudometric on `{}`?", dist.name, vars.set);
+
+    for law in pseudometric_laws(vars, dist, add, zero) {
+        law.assert();
+    }
+}
+
+/// Asserts that the distance function `dist` is an [ultrametric].
+///
+/// It must hold:
+/// - `dist` is non-negative ([`non_negative`])
+/// - `dist` fulfills the identity of indiscernibles ([`identity_of_indiscernibles`])
+/// - `dist` is symmetric ([`fun::commutative`])
+/// - `dist` fulfills the strong triangle inequality ([`ultrametric_inequality`])
+///
+/// [ultrametric]: https://en.wikipedia.org/wiki/Ultrametric_space
+pub fn ultrametric<S, D, F>(vars: Vars<S, 3>, dist: Fun2<F>, zero: Elem<D>)
+where
+    S: Debug + Clone + PartialEq,
+    D: Debug + Clone + PartialOrd,
+    F: Fn(S, S) -> D,
+{
+    hint_section!("Is `{}` an ultrametric on `{}`?", dist.name, vars.set);
+
+    for law in ultrametric_laws(vars, dist, zero) {
+        law.assert();
+    }
+}
+
+/// Asserts that the distance function `dist` is a [quasimetric], i.e. a metric that is not
+/// necessarily symmetric.
+///
+/// It must hold:
+/// - `dist` is non-negative ([`non_negative`])
+/// - `dist` fulfills the identity of indiscernibles ([`identity_of_indiscernibles`])
+/// - `dist` fulfills the triangle inequality ([`triangle_inequality`])
+///
+/// [quasimetric]: https://en.wikipedia.org/wiki/Metric_space#Quasimetrics
+pub fn quasimetric<S, D, F, A>(vars: Vars<S, 3>, dist: Fun2<F>, add: Fun2<A>, zero: Elem<D>)
+where
+    S: Debug + Clone + PartialEq,
+    D: Debug + Clone + PartialOrd,
+    F: Fn(S, S) -> D,
+    A: Fn(D, D) -> D,
+{
+    hint_section!("Is `{}` a quasimetric on `{}`?", dist.name, vars.set);
+
+    for law in quasimetric_laws(vars, dist, add, zero) {
+        law.assert();
+    }
+}
+
+/// Returns the laws of a [metric] (see [`metric`]).
+///
+/// [metric]: https://en.wikipedia.org/wiki/Metric_space
+pub fn metric_laws<'a, S, D, F, A>(
+    vars: Vars<'a, S, 3>,
+    dist: Fun2<'a, F>,
+    add: Fun2<'a, A>,
+    zero: Elem<'a, D>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    D: Debug + Clone + PartialOrd + 'a,
+    F: Fn(S, S) -> D + 'a,
+    A: Fn(D, D) -> D + 'a,
+{
+    let dist = ("dist", &Rc::new(dist));
+    let zero = ("zero", zero);
+
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![
+        non_negative_law(vars_2.clone(), dist, zero.clone()),
+        identity_of_indiscernibles_law(vars_2.clone(), dist, zero),
+        fun::commutative_law(vars_2, dist),
+        triangle_inequality_law(vars_3, dist, ("add", &Rc::new(add))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns the laws of a [pseudometric] (see [`pseudometric`]).
+///
+/// [pseudometric]: https://en.wikipedia.org/wiki/Pseudometric_space
+pub fn pseudometric_laws<'a, S, D, F, A>(
+    vars: Vars<'a, S, 3>,
+    dist: Fun2<'a, F>,
+    add: Fun2<'a, A>,
+    zero: Elem<'a, D>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    D: Debug + Clone + PartialOrd + 'a,
+    F: Fn(S, S) -> D + 'a,
+    A: Fn(D, D) -> D + 'a,
+{
+    let dist = ("dist", &Rc::new(dist));
+    let zero = ("zero", zero);
+
+    let [a, b, c] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [a.clone()]);
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![
+        non_negative_law(vars_2.clone(), dist, zero.clone()),
+        zero_self_distance_law(vars_1, dist, zero),
+        fun::commutative_law(vars_2, dist),
+        triangle_inequality_law(vars_3, dist, ("add", &Rc::new(add))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns the laws of an [ultrametric] (see [`ultrametric`]).
+///
+/// [ultrametric]: https://en.wikipedia.org/wiki/Ultrametric_space
+pub fn ultrametric_laws<'a, S, D, F>(
+    vars: Vars<'a, S, 3>,
+    dist: Fun2<'a, F>,
+    zero: Elem<'a, D>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    D: Debug + Clone + PartialOrd + 'a,
+    F: Fn(S, S) -> D + 'a,
+{
+    let dist = ("dist", &Rc::new(dist));
+    let zero = ("zero", zero);
+
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![
+        non_negative_law(vars_2.clone(), dist, zero.clone()),
+        identity_of_indiscernibles_law(vars_2.clone(), dist, zero),
+        fun::commutative_law(vars_2, dist),
+        ultrametric_inequality_law(vars_3, dist),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns the laws of a [quasimetric] (see [`quasimetric`]).
+///
+/// [quasimetric]: https://en.wikipedia.org/wiki/Metric_space#Quasimetrics
+pub fn quasimetric_laws<'a, S, D, F, A>(
+    vars: Vars<'a, S, 3>,
+    dist: Fun2<'a, F>,
+    add: Fun2<'a, A>,
+    zero: Elem<'a, D>,
+) -> Vec<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    D: Debug + Clone + PartialOrd + 'a,
+    F: Fn(S, S) -> D + 'a,
+    A: Fn(D, D) -> D + 'a,
+{
+    let dist = ("dist", &Rc::new(dist));
+    let zero = ("zero", zero);
+
+    let [a, b, c] = vars.elems;
+    let vars_2 = Vars::new(vars.set, [a.clone(), b.clone()]);
+    let vars_3 = Vars::new(vars.set, [a, b, c]);
+
+    vec![
+        non_negative_law(vars_2.clone(), dist, zero.clone()),
+        identity_of_indiscernibles_law(vars_2, dist, zero),
+        triangle_inequality_law(vars_3, dist, ("add", &Rc::new(add))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn non_negative_law<'a, S, D, F>(
+    vars: Vars<'a, S, 2>,
+    (dist_id, dist): (&str, &Rc<Fun2<'a, F>>),
+    (_, zero): (&str, Elem<'a, D>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    D: Debug + Clone + PartialOrd + 'a,
+    F: Fn(S, S) -> D + 'a,
+{
+    law::build(format!("{}_is_non_negative", dist_id), |id| {
+        let [a, b] = law::var_names(&vars);
+        let name = non_negative_name(dist.name);
+        let statement = law::le(zero.name, dist.label(a, b));
+        let dist = Rc::clone(dist);
+
+        Law::new(id, name, statement, 2, move || {
+            non_negative(vars.clone(), Fun2::as_ref(&dist), zero.clone())
+        })
+    })
+}
+
+fn zero_self_distance_law<'a, S, D, F>(
+    vars: Vars<'a, S, 1>,
+    (dist_id, dist): (&str, &Rc<Fun2<'a, F>>),
+    (_, zero): (&str, Elem<'a, D>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    D: Debug + Clone + PartialEq + 'a,
+    F: Fn(S, S) -> D + 'a,
+{
+    law::build(format!("{}_has_zero_self_distance", dist_id), |id| {
+        let [a] = law::var_names(&vars);
+        let name = zero_self_distance_name(dist.name);
+        let statement = law::eq(dist.label(a, a), zero.name);
+        let dist = Rc::clone(dist);
+
+        Law::new(id, name, statement, 1, move || {
+            zero_self_distance(vars.clone(), Fun2::as_ref(&dist), zero.clone())
+        })
+    })
+}
+
+fn identity_of_indiscernibles_law<'a, S, D, F>(
+    vars: Vars<'a, S, 2>,
+    (dist_id, dist): (&str, &Rc<Fun2<'a, F>>),
+    (_, zero): (&str, Elem<'a, D>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + PartialEq + 'a,
+    D: Debug + Clone + PartialEq + 'a,
+    F: Fn(S, S) -> D + 'a,
+{
+    law::build(
+        format!("{}_fulfills_identity_of_indiscernibles", dist_id),
+        |id| {
+            let [a, b] = law::var_names(&vars);
+            let name = identity_of_indiscernibles_name(dist.name);
+            let statement = format!(
+                "{}, {}",
+                law::eq(dist.label(a, a), zero.name),
+                law::iff(law::eq(dist.label(a, b), zero.name), law::eq(a, b)),
+            );
+            let dist = Rc::clone(dist);
+
+            Law::new(id, name, statement, 2, move || {
+                identity_of_indiscernibles(vars.clone(), Fun2::as_ref(&dist), zero.clone())
+            })
+        },
+    )
+}
+
+fn triangle_inequality_law<'a, S, D, F, A>(
+    vars: Vars<'a, S, 3>,
+    (dist_id, dist): (&str, &Rc<Fun2<'a, F>>),
+    (_, add): (&str, &Rc<Fun2<'a, A>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    D: Debug + PartialOrd + 'a,
+    F: Fn(S, S) -> D + 'a,
+    A: Fn(D, D) -> D + 'a,
+{
+    law::build(format!("{}_fulfills_triangle_inequality", dist_id), |id| {
+        let [a, b, c] = law::var_names(&vars);
+        let name = triangle_inequality_name(dist.name, add.name);
+        let statement = law::le(
+            dist.label(a, c),
+            add.label(dist.label(a, b), dist.label(b, c)),
+        );
+        let dist = Rc::clone(dist);
+        let add = Rc::clone(add);
+
+        Law::new(id, name, statement, 3, move || {
+            triangle_inequality(vars.clone(), Fun2::as_ref(&dist), Fun2::as_ref(&add))
+        })
+    })
+}
+
+fn ultrametric_inequality_law<'a, S, D, F>(
+    vars: Vars<'a, S, 3>,
+    (dist_id, dist): (&str, &Rc<Fun2<'a, F>>),
+) -> Option<Law<'a>>
+where
+    S: Debug + Clone + 'a,
+    D: Debug + PartialOrd + 'a,
+    F: Fn(S, S) -> D + 'a,
+{
+    law::build(
+        format!("{}_fulfills_strong_triangle_inequality", dist_id),
+        |id| {
+            let [a, b, c] = law::var_names(&vars);
+            let name = ultrametric_inequality_name(dist.name);
+            let statement = law::le(
+                dist.label(a, c),
+                max_fun::<()>().label(dist.label(a, b), dist.label(b, c)),
+            );
+            let dist = Rc::clone(dist);
+
+            Law::new(id, name, statement, 3, move || {
+                ultrametric_inequality(vars.clone(), Fun2::as_ref(&dist))
+            })
+        },
+    )
+}
+
+fn max_fun<D: PartialOrd>() -> Fun2<'static, impl Fn(D, D) -> D> {
+    Fun2::new("max", |x: D, y: D| if x < y { y } else { x })
+}
+
+fn non_negative_name(dist: &str) -> String {
+    format!("Is `{}` non-negative?", dist)
+}
+
+fn zero_self_distance_name(dist: &str) -> String {
+    format!("Is the distance `{}` of each element to itself zero?", dist)
+}
+
+fn identity_of_indiscernibles_name(dist: &str) -> String {
+    format!("Does `{}` fulfill the identity of indiscernibles?", dist)
+}
+
+fn triangle_inequality_name(dist: &str, add: &str) -> String {
+    format!(
+        "Does `{}` fulfill the triangle inequality regarding to `{}`?",
+        dist, add
+    )
+}
+
+fn ultrametric_inequality_name(dist: &str) -> String {
+    format!("Does `{}` fulfill the strong triangle inequality?", dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{props, Elem, Fun2, Set};
+
+    #[test]
+    fn metric_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-10,10]", dice::i64(-10..=10));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let dist = Fun2::new("dist", |x: i64, y: i64| (x - y).unsigned_abs());
+            let add = Fun2::infix("+", |x, y| x + y);
+            let zero = Elem::new("zero", 0);
+            props::metric::metric(vars, dist, add, zero);
+        })
+    }
+
+    #[test]
+    fn pseudometric_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i64² ∩ [-10,10]²", dice::array(dice::i64(-10..=10)));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let dist = Fun2::new("dist_0", |[x, _]: [i64; 2], [y, _]: [i64; 2]| {
+                (x - y).unsigned_abs()
+            });
+            let add = Fun2::infix("+", |x, y| x + y);
+            let zero = Elem::new("zero", 0);
+            props::metric::pseudometric(vars, dist, add, zero);
+        })
+    }
+
+    #[test]
+    fn ultrametric_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-100,100]", dice::i64(-100..=100));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let dist = Fun2::new("dist_2", |x: i64, y: i64| {
+                if x == y {
+                    0.0
+                } else {
+                    0.5f64.powi((x - y).trailing_zeros() as i32)
+                }
+            });
+            let zero = Elem::new("zero", 0.0);
+            props::metric::ultrametric(vars, dist, zero);
+        })
+    }
+
+    #[test]
+    fn quasimetric_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i64 ∩ [-10,10]", dice::i64(-10..=10));
+            let vars = fate.roll(set.vars(["x", "y", "z"]));
+            let dist = Fun2::new(
+                "climb",
+                |x: i64, y: i64| {
+                    if x <= y {
+                        y - x
+                    } else {
+                        2 * (x - y)
+                    }
+                },
+            );
+            let add = Fun2::infix("+", |x, y| x + y);
+            let zero = Elem::new("zero", 0);
+            props::metric::quasimetric(vars, dist, add, zero);
+        })
+    }
+}
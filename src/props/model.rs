@@ -0,0 +1,261 @@
+//! Properties for [model-based testing], i.e. comparing a system under test with a simpler
+//! reference implementation over whole sequences of operations.
+//!
+//! A [`Command`] describes an operation with a [`Set`] for its argument. It's applied to the
+//! system under test and to the model by two separate functions that must return the same
+//! observable result of type `R`. The generator [`step`] chooses a command and an argument
+//! randomly, hence sequences of steps can be generated with e.g. [`dicetest::dice::vec`].
+//!
+//! Concurrent data structures can be compared with a sequential model via
+//! [`linearizable`](crate::props::linearizable).
+//!
+//! # Examples
+//!
+//! ```
+//! use diceprop::props::model::{self, Command};
+//! use diceprop::{Elem, Set};
+//! use dicetest::prelude::*;
+//! use std::collections::BTreeMap;
+//!
+//! Dicetest::repeatedly().run(|mut fate| {
+//!     let commands = [
+//!         Command::new(
+//!             "insert",
+//!             Set::new("(u8, u8)", dice::zip().two(dice::u8(..), dice::u8(..))),
+//!             |sut: &mut Vec<(u8, u8)>, (k, v)| {
+//!                 let old = sut.iter().position(|&(l, _)| l == k).map(|i| sut.remove(i).1);
+//!                 sut.push((k, v));
+//!                 old
+//!             },
+//!             |model: &mut BTreeMap<u8, u8>, (k, v)| model.insert(k, v),
+//!         ),
+//!         Command::new(
+//!             "get",
+//!             Set::new("u8", dice::u8(..)),
+//!             |sut: &mut Vec<(u8, u8)>, k| sut.iter().find(|&&(l, _)| l == k).map(|&(_, v)| v),
+//!             |model: &mut BTreeMap<u8, u8>, k| model.get(&k).copied(),
+//!         ),
+//!     ];
+//!
+//!     let set = Set::new("Vec<Step>", dice::vec(model::step(&commands), ..));
+//!     let vars = fate.roll(set.vars(["steps"]));
+//!     let sut = Elem::new("assoc_list", Vec::new());
+//!     let model = Elem::new("btree_map", BTreeMap::new());
+//!     model::equivalent(vars, sut, model);
+//! })
+//! ```
+//!
+//! [model-based testing]: https://en.wikipedia.org/wiki/Model-based_testing
+
+use dicetest::{dice, hint, hint_section, Die, Fate};
+use std::fmt::{self, Debug};
+use std::rc::Rc;
+
+use crate::{ops, Elem, Eval, Set, Vars};
+
+/// An operation that can be applied to a system under test of type `T` and to a model of type
+/// `M`. Both return an observable result of type `R`.
+pub struct Command<'a, T, M, R> {
+    /// The name of the command.
+    pub name: &'a str,
+    roll_step: Box<RollStep<'a, T, M, R>>,
+}
+
+type RollStep<'a, T, M, R> = dyn Fn(&mut Fate) -> Step<'a, T, M, R> + 'a;
+
+impl<'a, T, M, R> Command<'a, T, M, R> {
+    /// Creates a [`Command`] with the given name.
+    ///
+    /// The arguments are chosen from `args`. The function `sut` applies the command to the
+    /// system under test, the function `model` applies it to the model.
+    pub fn new<A, D, F, G>(name: &'a str, args: Set<'a, A, D>, sut: F, model: G) -> Self
+    where
+        A: Debug + Clone + 'a,
+        D: Die<A> + 'a,
+        F: Fn(&mut T, A) -> R + 'a,
+        G: Fn(&mut M, A) -> R + 'a,
+    {
+        let arg_die = args.elem_die;
+        let sut = Rc::new(sut);
+        let model = Rc::new(model);
+
+        let roll_step = move |fate: &mut Fate| {
+            let arg = fate.roll(&arg_die);
+            let label = step_label(name, &arg);
+            let sut_arg = arg.clone();
+            let sut = Rc::clone(&sut);
+            let model = Rc::clone(&model);
+
+            Step {
+                label,
+                sut: Rc::new(move |t: &mut T| sut(t, sut_arg.clone())),
+                model: Rc::new(move |m: &mut M| model(m, arg.clone())),
+            }
+        };
+
+        Self {
+            name,
+            roll_step: Box::new(roll_step),
+        }
+    }
+}
+
+/// A [`Command`] applied to an argument.
+///
+/// It's labelled like a function call, e.g. `get(42)` or `insert(1, 2)`.
+pub struct Step<'a, T, M, R> {
+    label: String,
+    sut: Rc<dyn Fn(&mut T) -> R + 'a>,
+    model: Rc<dyn Fn(&mut M) -> R + 'a>,
+}
+
+impl<'a, T, M, R> Clone for Step<'a, T, M, R> {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            sut: Rc::clone(&self.sut),
+            model: Rc::clone(&self.model),
+        }
+    }
+}
+
+impl<'a, T, M, R> Debug for Step<'a, T, M, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+/// Returns a generator that chooses one of the `commands` and an argument for it.
+///
+/// # Panics
+///
+/// The generator panics if `commands` is empty.
+pub fn step<'a, 'b, T, M, R>(
+    commands: &'b [Command<'a, T, M, R>],
+) -> impl Die<Step<'a, T, M, R>> + 'b {
+    dice::from_fn(move |mut fate| {
+        let index = fate.roll(dice::index_of(commands));
+        (commands[index].roll_step)(&mut fate)
+    })
+}
+
+/// Asserts that the system under test `sut` behaves like the model `model`.
+///
+/// The steps are applied one after another to `sut` and to `model`. Each step is logged as
+/// hint section, hence the hints contain the trace of all steps until the failing one.
+///
+/// For all `steps` of `vars.set` it must hold:
+/// - for each `step` of `steps`: `sut.step == model.step`
+pub fn equivalent<T, M, R>(vars: Vars<Vec<Step<T, M, R>>, 1>, sut: Elem<T>, model: Elem<M>)
+where
+    T: Debug,
+    M: Debug,
+    R: Debug + PartialEq,
+{
+    hint_section!("Does `{}` behave like `{}`?", sut.name, model.name);
+
+    let [steps] = vars.eval();
+    let mut sut = sut.eval();
+    let mut model = model.eval();
+
+    for (i, step) in steps.value.iter().enumerate() {
+        hint_section!("Step {}: `{}`", i + 1, step.label);
+
+        let sut_label = format!("{}.{}", sut.label, step.label);
+        let model_label = format!("{}.{}", model.label, step.label);
+        let sut_result = Eval::labelled(&sut_label, (step.sut)(&mut sut.value));
+        hint!("{} = {:?}", sut_result.label, sut_result.value);
+        let model_result = Eval::labelled(&model_label, (step.model)(&mut model.value));
+        hint!("{} = {:?}", model_result.label, model_result.value);
+
+        ops::assert(ops::eq(sut_result.as_ref(), model_result.as_ref()));
+    }
+}
+
+fn step_label<A: Debug>(name: &str, arg: &A) -> String {
+    let arg = format!("{:?}", arg);
+
+    // Tuples are already enclosed in parentheses, e.g. `insert(1, 2)` or `len()`
+    if arg.starts_with('(') && arg.ends_with(')') {
+        format!("{}{}", name, arg)
+    } else {
+        format!("{}({})", name, arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+    use std::collections::BTreeMap;
+
+    use crate::props::model::{self, Command};
+    use crate::{check, Elem, Set};
+
+    type AssocList = Vec<(u8, u8)>;
+
+    fn remove_first(sut: &mut AssocList, k: u8) -> Option<u8> {
+        let index = sut.iter().position(|&(l, _)| l == k)?;
+        Some(sut.remove(index).1)
+    }
+
+    #[test]
+    fn equivalent_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let commands = [
+                Command::new(
+                    "insert",
+                    Set::new("(u8, u8)", dice::zip().two(dice::u8(..10), dice::u8(..))),
+                    |sut: &mut AssocList, (k, v)| {
+                        let old = remove_first(sut, k);
+                        sut.push((k, v));
+                        old
+                    },
+                    |model: &mut BTreeMap<u8, u8>, (k, v)| model.insert(k, v),
+                ),
+                Command::new(
+                    "get",
+                    Set::new("u8", dice::u8(..10)),
+                    |sut: &mut AssocList, k| sut.iter().find(|&&(l, _)| l == k).map(|&(_, v)| v),
+                    |model: &mut BTreeMap<u8, u8>, k| model.get(&k).copied(),
+                ),
+                Command::new(
+                    "remove",
+                    Set::new("u8", dice::u8(..10)),
+                    remove_first,
+                    |model: &mut BTreeMap<u8, u8>, k| model.remove(&k),
+                ),
+            ];
+
+            let set = Set::new("Vec<Step>", dice::vec(model::step(&commands), ..));
+            let vars = fate.roll(set.vars(["steps"]));
+            let sut = Elem::new("assoc_list", Vec::new());
+            let model = Elem::new("btree_map", BTreeMap::new());
+            model::equivalent(vars, sut, model);
+        })
+    }
+
+    #[test]
+    fn equivalent_detects_wrong_result() {
+        Dicetest::once().run(|mut fate| {
+            let commands = [Command::new(
+                "remove",
+                Set::new("u8", dice::just(0)),
+                |sut: &mut AssocList, k| sut.iter().find(|&&(l, _)| l == k).map(|&(_, v)| v),
+                |model: &mut BTreeMap<u8, u8>, k| model.remove(&k),
+            )];
+
+            let set = Set::new("Vec<Step>", dice::vec(model::step(&commands), 2..=2));
+            let vars = fate.roll(set.vars(["steps"]));
+            let sut = Elem::new("assoc_list", vec![(0, 0)]);
+            let model = Elem::new("btree_map", BTreeMap::from([(0, 0)]));
+
+            let failure = check(|| model::equivalent(vars, sut, model)).unwrap_err();
+
+            assert_eq!(failure.section.unwrap(), "Step 2: `remove(0)`");
+            assert_eq!(
+                failure.assertion,
+                "(assoc_list.remove(0) == btree_map.remove(0))"
+            );
+        })
+    }
+}
@@ -0,0 +1,651 @@
+//! Properties for [monads] and [applicative functors], i.e. containers with a `bind` or a
+//! `zip_with` function.
+//!
+//! Rust has no higher-kinded types, hence `bind` and `zip_with` are represented by functions
+//! that take containers of type `C` and a reference to a function on the elements of type `A`.
+//!
+//! [monads]: https://en.wikipedia.org/wiki/Monad_(functional_programming)
+//! [applicative functors]: https://en.wikipedia.org/wiki/Applicative_functor
+
+use dicetest::hint_section;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::law::{self, Law};
+use crate::{ops, Eval, Fun1, Fun2, Fun3, Vars};
+
+/// Asserts that `pure` is the left identity of `bind`.
+///
+/// For all `a` of `elem_vars.set` it must hold:
+/// - `bind(pure(a), f) == f(a)`
+pub fn left_identity<A, C, P, B, F>(elem_vars: Vars<A, 1>, pure: Fun1<P>, bind: Fun2<B>, f: Fun1<F>)
+where
+    A: Debug + Clone,
+    C: Debug + PartialEq,
+    P: FnOnce(A) -> C,
+    B: FnOnce(C, &dyn Fn(A) -> C) -> C,
+    F: Fn(A) -> C,
+{
+    hint_section!("{}", left_identity_name(pure.name, bind.name));
+
+    let [a] = elem_vars.eval();
+    let f_fun = Eval::labelled(f.name, f.f() as &dyn Fn(A) -> C);
+
+    ops::assert(ops::eq(
+        bind.eval_once(pure.eval_once(a.clone()), f_fun).as_ref(),
+        f.eval(a).as_ref(),
+    ));
+}
+
+/// Asserts that `pure` is the right identity of `bind`.
+///
+/// For all `m` of `vars.set` it must hold:
+/// - `bind(m, pure) == m`
+pub fn right_identity<A, C, P, B>(vars: Vars<C, 1>, pure: Fun1<P>, bind: Fun2<B>)
+where
+    C: Debug + Clone + PartialEq,
+    P: Fn(A) -> C,
+    B: FnOnce(C, &dyn Fn(A) -> C) -> C,
+{
+    hint_section!("{}", right_identity_name(pure.name, bind.name));
+
+    let [m] = vars.eval();
+    let pure_fun = Eval::labelled(pure.name, pure.f() as &dyn Fn(A) -> C);
+
+    ops::assert(ops::eq(
+        bind.eval_once(m.clone(), pure_fun).as_ref(),
+        m.as_ref(),
+    ));
+}
+
+/// Asserts that `bind` is associative regarding to the functions `f` and `g`.
+///
+/// For all `m` of `vars.set` it must hold:
+/// - `bind(bind(m, f), g) == bind(m, (x ↦ bind(f(x), g)))`
+pub fn associative<A, C, B, F, G>(vars: Vars<C, 1>, bind: Fun2<B>, f: Fun1<F>, g: Fun1<G>)
+where
+    C: Debug + Clone + PartialEq,
+    B: Fn(C, &dyn Fn(A) -> C) -> C,
+    F: Fn(A) -> C,
+    G: Fn(A) -> C,
+{
+    hint_section!("{}", associative_name(bind.name, f.name, g.name));
+
+    let [m] = vars.eval();
+    let f_fun = Eval::labelled(f.name, f.f() as &dyn Fn(A) -> C);
+    let g_fun = Eval::labelled(g.name, g.f() as &dyn Fn(A) -> C);
+    let f_then_g = |x| (bind.f())((f.f())(x), g.f());
+    let f_then_g_name = f_then_g_label(bind.name, f.name, g.name);
+    let f_then_g_fun = Eval::labelled(&f_then_g_name, &f_then_g as &dyn Fn(A) -> C);
+
+    ops::assert(ops::eq(
+        bind.eval(bind.eval(m.clone(), f_fun), g_fun).as_ref(),
+        bind.eval(m, f_then_g_fun).as_ref(),
+    ));
+}
+
+/// Asserts that `(pure, bind)` is a [monad].
+///
+/// It must hold:
+/// - `pure` is the left identity of `bind` ([`left_identity`])
+/// - `pure` is the right identity of `bind` ([`right_identity`])
+/// - `bind` is associative ([`associative`])
+///
+/// [monad]: https://en.wikipedia.org/wiki/Monad_(functional_programming)
+pub fn monad<A, C, P, B, F, G>(
+    elem_vars: Vars<A, 1>,
+    vars: Vars<C, 1>,
+    pure: Fun1<P>,
+    bind: Fun2<B>,
+    f: Fun1<F>,
+    g: Fun1<G>,
+) where
+    A: Debug + Clone,
+    C: Debug + Clone + PartialEq,
+    P: Fn(A) -> C,
+    B: Fn(C, &dyn Fn(A) -> C) -> C,
+    F: Fn(A) -> C,
+    G: Fn(A) -> C,
+{
+    hint_section!(
+        "Is `({}, {})` a monad on `{}`?",
+        pure.name,
+        bind.name,
+        vars.set,
+    );
+
+    for law in monad_laws(elem_vars, vars, pure, bind, f, g) {
+        law.assert();
+    }
+}
+
+/// Returns the laws of a [monad] (see [`monad`]).
+///
+/// [monad]: https://en.wikipedia.org/wiki/Monad_(functional_programming)
+pub fn monad_laws<'a, A, C, P, B, F, G>(
+    elem_vars: Vars<'a, A, 1>,
+    vars: Vars<'a, C, 1>,
+    pure: Fun1<'a, P>,
+    bind: Fun2<'a, B>,
+    f: Fun1<'a, F>,
+    g: Fun1<'a, G>,
+) -> Vec<Law<'a>>
+where
+    A: Debug + Clone + 'a,
+    C: Debug + Clone + PartialEq + 'a,
+    P: Fn(A) -> C + 'a,
+    B: Fn(C, &dyn Fn(A) -> C) -> C + 'a,
+    F: Fn(A) -> C + 'a,
+    G: Fn(A) -> C + 'a,
+{
+    let pure = ("pure", &Rc::new(pure));
+    let bind = ("bind", &Rc::new(bind));
+    let f = ("f", &Rc::new(f));
+
+    vec![
+        left_identity_law(elem_vars, pure, bind, f),
+        right_identity_law(vars.clone(), pure, bind),
+        associative_law(vars, bind, f, ("g", &Rc::new(g))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Asserts that `pure` is the identity of `zip_with`.
+///
+/// For all `a` of `elem_vars.set` and `xs` of `vars.set` it must hold:
+/// - `zip_with(pure(a), xs, snd) == xs`
+/// - `zip_with(xs, pure(a), fst) == xs`
+pub fn zip_identity<A, C, P, Z>(
+    elem_vars: Vars<A, 1>,
+    vars: Vars<C, 1>,
+    pure: Fun1<P>,
+    zip_with: Fun3<Z>,
+) where
+    A: Debug + Clone,
+    C: Debug + Clone + PartialEq,
+    P: Fn(A) -> C,
+    Z: Fn(C, C, &dyn Fn(A, A) -> A) -> C,
+{
+    hint_section!("{}", zip_identity_name(pure.name, zip_with.name));
+
+    let [a] = elem_vars.eval();
+    let [xs] = vars.eval();
+    let fst: &dyn Fn(A, A) -> A = &|x, _| x;
+    let snd: &dyn Fn(A, A) -> A = &|_, y| y;
+    let fst = Eval::labelled("fst", fst);
+    let snd = Eval::labelled("snd", snd);
+
+    ops::assert(ops::eq(
+        zip_with
+            .eval(pure.eval(a.clone()), xs.clone(), snd)
+            .as_ref(),
+        xs.as_ref(),
+    ));
+    ops::assert(ops::eq(
+        zip_with.eval(xs.clone(), pure.eval(a), fst).as_ref(),
+        xs.as_ref(),
+    ));
+}
+
+/// Asserts that `zip_with` is associative regarding to the associative function `op`.
+///
+/// For all `xs`, `ys`, `zs` of `vars.set` it must hold:
+/// - `zip_with(zip_with(xs, ys, op), zs, op) == zip_with(xs, zip_with(ys, zs, op), op)`
+pub fn zip_associative<A, C, Z, O>(vars: Vars<C, 3>, zip_with: Fun3<Z>, op: Fun2<O>)
+where
+    C: Debug + Clone + PartialEq,
+    Z: Fn(C, C, &dyn Fn(A, A) -> A) -> C,
+    O: Fn(A, A) -> A,
+{
+    hint_section!("{}", zip_associative_name(zip_with.name, op.name));
+
+    let [xs, ys, zs] = vars.eval();
+    let op_fun = || Eval::labelled(op.name, op.f() as &dyn Fn(A, A) -> A);
+
+    ops::assert(ops::eq(
+        zip_with
+            .eval(
+                zip_with.eval(xs.clone(), ys.clone(), op_fun()),
+                zs.clone(),
+                op_fun(),
+            )
+            .as_ref(),
+        zip_with
+            .eval(xs, zip_with.eval(ys, zs, op_fun()), op_fun())
+            .as_ref(),
+    ));
+}
+
+/// Asserts that `pure` is a homomorphism regarding to `zip_with` and the function `op`.
+///
+/// For all `a`, `b` of `elem_vars.set` it must hold:
+/// - `zip_with(pure(a), pure(b), op) == pure(op(a, b))`
+pub fn homomorphism<A, C, P, Z, O>(
+    elem_vars: Vars<A, 2>,
+    pure: Fun1<P>,
+    zip_with: Fun3<Z>,
+    op: Fun2<O>,
+) where
+    A: Debug + Clone,
+    C: Debug + PartialEq,
+    P: Fn(A) -> C,
+    Z: FnOnce(C, C, &dyn Fn(A, A) -> A) -> C,
+    O: Fn(A, A) -> A,
+{
+    hint_section!("{}", homomorphism_name(pure.name, zip_with.name, op.name));
+
+    let [a, b] = elem_vars.eval();
+    let op_fun = Eval::labelled(op.name, op.f() as &dyn Fn(A, A) -> A);
+
+    ops::assert(ops::eq(
+        zip_with
+            .eval_once(pure.eval(a.clone()), pure.eval(b.clone()), op_fun)
+            .as_ref(),
+        pure.eval(op.eval(a, b)).as_ref(),
+    ));
+}
+
+/// Asserts that `(pure, zip_with)` is an [applicative functor] in its monoidal presentation.
+///
+/// It must hold:
+/// - `pure` is the identity of `zip_with` ([`zip_identity`])
+/// - `zip_with` is associative regarding to `op` ([`zip_associative`])
+/// - `pure` is a homomorphism ([`homomorphism`])
+///
+/// The function `op` must be associative.
+///
+/// [applicative functor]: https://en.wikipedia.org/wiki/Applicative_functor
+pub fn applicative<A, C, P, Z, O>(
+    elem_vars: Vars<A, 2>,
+    vars: Vars<C, 3>,
+    pure: Fun1<P>,
+    zip_with: Fun3<Z>,
+    op: Fun2<O>,
+) where
+    A: Debug + Clone,
+    C: Debug + Clone + PartialEq,
+    P: Fn(A) -> C,
+    Z: Fn(C, C, &dyn Fn(A, A) -> A) -> C,
+    O: Fn(A, A) -> A,
+{
+    hint_section!(
+        "Is `({}, {})` an applicative functor on `{}`?",
+        pure.name,
+        zip_with.name,
+        vars.set,
+    );
+
+    for law in applicative_laws(elem_vars, vars, pure, zip_with, op) {
+        law.assert();
+    }
+}
+
+/// Returns the laws of an [applicative functor] (see [`applicative`]).
+///
+/// [applicative functor]: https://en.wikipedia.org/wiki/Applicative_functor
+pub fn applicative_laws<'a, A, C, P, Z, O>(
+    elem_vars: Vars<'a, A, 2>,
+    vars: Vars<'a, C, 3>,
+    pure: Fun1<'a, P>,
+    zip_with: Fun3<'a, Z>,
+    op: Fun2<'a, O>,
+) -> Vec<Law<'a>>
+where
+    A: Debug + Clone + 'a,
+    C: Debug + Clone + PartialEq + 'a,
+    P: Fn(A) -> C + 'a,
+    Z: Fn(C, C, &dyn Fn(A, A) -> A) -> C + 'a,
+    O: Fn(A, A) -> A + 'a,
+{
+    let pure = ("pure", &Rc::new(pure));
+    let zip_with = ("zip_with", &Rc::new(zip_with));
+    let op = ("op", &Rc::new(op));
+
+    let [a, b] = elem_vars.elems;
+    let elem_vars_1 = Vars::new(elem_vars.set, [a.clone()]);
+    let elem_vars_2 = Vars::new(elem_vars.set, [a, b]);
+    let [xs, ys, zs] = vars.elems;
+    let vars_1 = Vars::new(vars.set, [xs.clone()]);
+    let vars_3 = Vars::new(vars.set, [xs, ys, zs]);
+
+    vec![
+        zip_identity_law(elem_vars_1, vars_1, pure, zip_with),
+        zip_associative_law(vars_3, zip_with, op),
+        homomorphism_law(elem_vars_2, pure, zip_with, op),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn left_identity_law<'a, A, C, P, B, F>(
+    elem_vars: Vars<'a, A, 1>,
+    (pure_id, pure): (&str, &Rc<Fun1<'a, P>>),
+    (bind_id, bind): (&str, &Rc<Fun2<'a, B>>),
+    (_, f): (&str, &Rc<Fun1<'a, F>>),
+) -> Option<Law<'a>>
+where
+    A: Debug + Clone + 'a,
+    C: Debug + PartialEq + 'a,
+    P: Fn(A) -> C + 'a,
+    B: Fn(C, &dyn Fn(A) -> C) -> C + 'a,
+    F: Fn(A) -> C + 'a,
+{
+    let id = format!("{}_is_left_identity_of_{}", pure_id, bind_id);
+    law::build(id, |id| {
+        let [a] = law::var_names(&elem_vars);
+        let name = left_identity_name(pure.name, bind.name);
+        let statement = law::eq(bind.label(pure.label(a), f.name), f.label(a));
+        let pure = Rc::clone(pure);
+        let bind = Rc::clone(bind);
+        let f = Rc::clone(f);
+
+        Law::new(id, name, statement, 1, move || {
+            left_identity(
+                elem_vars.clone(),
+                Fun1::as_ref(&pure),
+                Fun2::as_ref(&bind),
+                Fun1::as_ref(&f),
+            )
+        })
+    })
+}
+
+fn right_identity_law<'a, A, C, P, B>(
+    vars: Vars<'a, C, 1>,
+    (pure_id, pure): (&str, &Rc<Fun1<'a, P>>),
+    (bind_id, bind): (&str, &Rc<Fun2<'a, B>>),
+) -> Option<Law<'a>>
+where
+    A: 'a,
+    C: Debug + Clone + PartialEq + 'a,
+    P: Fn(A) -> C + 'a,
+    B: Fn(C, &dyn Fn(A) -> C) -> C + 'a,
+{
+    let id = format!("{}_is_right_identity_of_{}", pure_id, bind_id);
+    law::build(id, |id| {
+        let [m] = law::var_names(&vars);
+        let name = right_identity_name(pure.name, bind.name);
+        let statement = law::eq(bind.label(m, pure.name), m);
+        let pure = Rc::clone(pure);
+        let bind = Rc::clone(bind);
+
+        Law::new(id, name, statement, 1, move || {
+            right_identity(vars.clone(), Fun1::as_ref(&pure), Fun2::as_ref(&bind))
+        })
+    })
+}
+
+fn associative_law<'a, A, C, B, F, G>(
+    vars: Vars<'a, C, 1>,
+    (bind_id, bind): (&str, &Rc<Fun2<'a, B>>),
+    (_, f): (&str, &Rc<Fun1<'a, F>>),
+    (_, g): (&str, &Rc<Fun1<'a, G>>),
+) -> Option<Law<'a>>
+where
+    A: 'a,
+    C: Debug + Clone + PartialEq + 'a,
+    B: Fn(C, &dyn Fn(A) -> C) -> C + 'a,
+    F: Fn(A) -> C + 'a,
+    G: Fn(A) -> C + 'a,
+{
+    law::build(format!("{}_is_associative", bind_id), |id| {
+        let [m] = law::var_names(&vars);
+        let name = associative_name(bind.name, f.name, g.name);
+        let f_then_g_name = f_then_g_label(bind.name, f.name, g.name);
+        let statement = law::eq(
+            bind.label(bind.label(m, f.name), g.name),
+            bind.label(m, f_then_g_name.as_str()),
+        );
+        let bind = Rc::clone(bind);
+        let f = Rc::clone(f);
+        let g = Rc::clone(g);
+
+        Law::new(id, name, statement, 1, move || {
+            associative(
+                vars.clone(),
+                Fun2::as_ref(&bind),
+                Fun1::as_ref(&f),
+                Fun1::as_ref(&g),
+            )
+        })
+    })
+}
+
+fn zip_identity_law<'a, A, C, P, Z>(
+    elem_vars: Vars<'a, A, 1>,
+    vars: Vars<'a, C, 1>,
+    (pure_id, pure): (&str, &Rc<Fun1<'a, P>>),
+    (zip_with_id, zip_with): (&str, &Rc<Fun3<'a, Z>>),
+) -> Option<Law<'a>>
+where
+    A: Debug + Clone + 'a,
+    C: Debug + Clone + PartialEq + 'a,
+    P: Fn(A) -> C + 'a,
+    Z: Fn(C, C, &dyn Fn(A, A) -> A) -> C + 'a,
+{
+    let id = format!("{}_is_identity_of_{}", pure_id, zip_with_id);
+    law::build(id, |id| {
+        let [a] = law::var_names(&elem_vars);
+        let [xs] = law::var_names(&vars);
+        let name = zip_identity_name(pure.name, zip_with.name);
+        let statement = [
+            law::eq(zip_with.label(pure.label(a), xs, "snd"), xs),
+            law::eq(zip_with.label(xs, pure.label(a), "fst"), xs),
+        ]
+        .join(", ");
+        let pure = Rc::clone(pure);
+        let zip_with = Rc::clone(zip_with);
+
+        Law::new(id, name, statement, 2, move || {
+            zip_identity(
+                elem_vars.clone(),
+                vars.clone(),
+                Fun1::as_ref(&pure),
+                Fun3::as_ref(&zip_with),
+            )
+        })
+    })
+}
+
+fn zip_associative_law<'a, A, C, Z, O>(
+    vars: Vars<'a, C, 3>,
+    (zip_with_id, zip_with): (&str, &Rc<Fun3<'a, Z>>),
+    (_, op): (&str, &Rc<Fun2<'a, O>>),
+) -> Option<Law<'a>>
+where
+    A: 'a,
+    C: Debug + Clone + PartialEq + 'a,
+    Z: Fn(C, C, &dyn Fn(A, A) -> A) -> C + 'a,
+    O: Fn(A, A) -> A + 'a,
+{
+    law::build(format!("{}_is_associative", zip_with_id), |id| {
+        let [xs, ys, zs] = law::var_names(&vars);
+        let name = zip_associative_name(zip_with.name, op.name);
+        let statement = law::eq(
+            zip_with.label(zip_with.label(xs, ys, op.name), zs, op.name),
+            zip_with.label(xs, zip_with.label(ys, zs, op.name), op.name),
+        );
+        let zip_with = Rc::clone(zip_with);
+        let op = Rc::clone(op);
+
+        Law::new(id, name, statement, 3, move || {
+            zip_associative(vars.clone(), Fun3::as_ref(&zip_with), Fun2::as_ref(&op))
+        })
+    })
+}
+
+fn homomorphism_law<'a, A, C, P, Z, O>(
+    elem_vars: Vars<'a, A, 2>,
+    (pure_id, pure): (&str, &Rc<Fun1<'a, P>>),
+    (_, zip_with): (&str, &Rc<Fun3<'a, Z>>),
+    (_, op): (&str, &Rc<Fun2<'a, O>>),
+) -> Option<Law<'a>>
+where
+    A: Debug + Clone + 'a,
+    C: Debug + PartialEq + 'a,
+    P: Fn(A) -> C + 'a,
+    Z: Fn(C, C, &dyn Fn(A, A) -> A) -> C + 'a,
+    O: Fn(A, A) -> A + 'a,
+{
+    law::build(format!("{}_is_homomorphism", pure_id), |id| {
+        let [a, b] = law::var_names(&elem_vars);
+        let name = homomorphism_name(pure.name, zip_with.name, op.name);
+        let statement = law::eq(
+            zip_with.label(pure.label(a), pure.label(b), op.name),
+            pure.label(op.label(a, b)),
+        );
+        let pure = Rc::clone(pure);
+        let zip_with = Rc::clone(zip_with);
+        let op = Rc::clone(op);
+
+        Law::new(id, name, statement, 2, move || {
+            homomorphism(
+                elem_vars.clone(),
+                Fun1::as_ref(&pure),
+                Fun3::as_ref(&zip_with),
+                Fun2::as_ref(&op),
+            )
+        })
+    })
+}
+
+fn f_then_g_label(bind: &str, f: &str, g: &str) -> String {
+    format!("(x ↦ {}({}(x), {}))", bind, f, g)
+}
+
+fn left_identity_name(pure: &str, bind: &str) -> String {
+    format!("Is `{}` left identity of `{}`?", pure, bind)
+}
+
+fn right_identity_name(pure: &str, bind: &str) -> String {
+    format!("Is `{}` right identity of `{}`?", pure, bind)
+}
+
+fn associative_name(bind: &str, f: &str, g: &str) -> String {
+    format!(
+        "Is `{}` associative regarding to `{}` and `{}`?",
+        bind, f, g
+    )
+}
+
+fn zip_identity_name(pure: &str, zip_with: &str) -> String {
+    format!("Is `{}` identity of `{}`?", pure, zip_with)
+}
+
+fn zip_associative_name(zip_with: &str, op: &str) -> String {
+    format!("Is `{}` associative regarding to `{}`?", zip_with, op)
+}
+
+fn homomorphism_name(pure: &str, zip_with: &str, op: &str) -> String {
+    format!(
+        "Is `{}` a homomorphism regarding to `{}` and `{}`?",
+        pure, zip_with, op
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{props, Fun1, Fun2, Fun3, Set};
+
+    fn bind(xs: Vec<u8>, f: &dyn Fn(u8) -> Vec<u8>) -> Vec<u8> {
+        xs.into_iter().flat_map(f).collect()
+    }
+
+    fn zip_with(xs: Vec<u8>, ys: Vec<u8>, f: &dyn Fn(u8, u8) -> u8) -> Vec<u8> {
+        xs.iter()
+            .flat_map(|&x| ys.iter().map(move |&y| f(x, y)))
+            .collect()
+    }
+
+    #[test]
+    fn monad_example() {
+        Dicetest::once().run(|mut fate| {
+            let elem_set = Set::new("u8", dice::u8(..));
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..10));
+            let elem_vars = fate.roll(elem_set.vars(["a"]));
+            let vars = fate.roll(set.vars(["m"]));
+            let pure = Fun1::new("pure", |x| vec![x]);
+            let bind = Fun2::new("bind", bind);
+            let f = Fun1::new("twice", |x: u8| vec![x, x]);
+            let g = Fun1::new("neighbors", |x: u8| {
+                vec![x.wrapping_sub(1), x.wrapping_add(1)]
+            });
+            props::monad::monad(elem_vars, vars, pure, bind, f, g);
+        })
+    }
+
+    #[test]
+    fn monad_laws_example() {
+        Dicetest::once().run(|mut fate| {
+            let elem_set = Set::new("u8", dice::u8(..));
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..10));
+            let elem_vars = fate.roll(elem_set.vars(["a"]));
+            let vars = fate.roll(set.vars(["m"]));
+            let pure = Fun1::new("pure", |x| vec![x]);
+            let bind = Fun2::new("bind", bind);
+            let f = Fun1::new("twice", |x: u8| vec![x, x]);
+            let g = Fun1::new("neighbors", |x: u8| {
+                vec![x.wrapping_sub(1), x.wrapping_add(1)]
+            });
+            let laws = props::monad::monad_laws(elem_vars, vars, pure, bind, f, g);
+
+            assert_eq!(laws[0].statement, "(bind(pure(a), twice) == twice(a))");
+            assert_eq!(laws[1].statement, "(bind(m, pure) == m)");
+            assert_eq!(
+                laws[2].statement,
+                "(bind(bind(m, twice), neighbors) == bind(m, (x ↦ bind(twice(x), neighbors))))"
+            );
+
+            for law in laws {
+                law.assert();
+            }
+        })
+    }
+
+    #[test]
+    fn applicative_example() {
+        Dicetest::once().run(|mut fate| {
+            let elem_set = Set::new("u8", dice::u8(..));
+            let set = Set::new("Vec<u8>", dice::vec(dice::u8(..), ..5));
+            let elem_vars = fate.roll(elem_set.vars(["a", "b"]));
+            let vars = fate.roll(set.vars(["xs", "ys", "zs"]));
+            let pure = Fun1::new("pure", |x| vec![x]);
+            let zip_with = Fun3::new("zip_with", zip_with);
+            let op = Fun2::infix("+", u8::wrapping_add);
+            props::monad::applicative(elem_vars, vars, pure, zip_with, op);
+        })
+    }
+
+    #[test]
+    fn applicative_laws_example() {
+        Dicetest::once().run(|mut fate| {
+            let elem_set = Set::new("u8", dice::u8(..));
+            let set = Set::new("Option<u8>", dice::option(dice::u8(..)));
+            let elem_vars = fate.roll(elem_set.vars(["a", "b"]));
+            let vars = fate.roll(set.vars(["xs", "ys", "zs"]));
+            let pure = Fun1::new("Some", Some);
+            let zip_with = Fun3::new(
+                "zip_with",
+                |xs: Option<u8>, ys: Option<u8>, f: &dyn Fn(u8, u8) -> u8| {
+                    xs.zip(ys).map(|(x, y)| f(x, y))
+                },
+            );
+            let op = Fun2::infix("+", u8::wrapping_add);
+            let laws = props::monad::applicative_laws(elem_vars, vars, pure, zip_with, op);
+
+            assert_eq!(
+                laws[2].statement,
+                "(zip_with(Some(a), Some(b), +) == Some((a + b)))"
+            );
+
+            for law in laws {
+                law.assert();
+            }
+        })
+    }
+}
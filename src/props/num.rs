@@ -0,0 +1,313 @@
+//! Properties for the numeric traits of [`num_traits`].
+//!
+//! This module is only available if the feature `num-traits` is enabled.
+
+use dicetest::hint_section;
+use num_traits::{Bounded, CheckedAdd, CheckedMul, CheckedSub, Float, One, Signed, Zero};
+use std::fmt::Debug;
+use std::num::FpCategory;
+use std::ops::{Add, Mul, Sub};
+
+use crate::props::binop::identity_elem;
+use crate::{ops, Elem, Eval, Fun1, Fun2, Vars};
+
+/// Asserts that [`Zero::zero`] is the [identity element] of `+`.
+///
+/// For all `a` of `vars.set` it must hold:
+/// - `is_zero(zero)`
+/// - `zero` is the identity element of `+` ([`identity_elem`])
+///
+/// [identity element]: https://en.wikipedia.org/wiki/Identity_element
+pub fn zero<S>(vars: Vars<S, 1>)
+where
+    S: Debug + Clone + PartialEq + Zero,
+{
+    hint_section!("Is `zero` the zero of `{}`?", vars.set);
+
+    let zero = Elem::new("zero", S::zero());
+    let is_zero = Fun1::new("is_zero", |x: &S| x.is_zero());
+
+    ops::assert(is_zero.eval(zero.as_ref().eval()));
+    identity_elem(vars, Fun2::infix("+", S::add), zero);
+}
+
+/// Asserts that [`One::one`] is the [identity element] of `*`.
+///
+/// For all `a` of `vars.set` it must hold:
+/// - `is_one(one)`
+/// - `one` is the identity element of `*` ([`identity_elem`])
+///
+/// [identity element]: https://en.wikipedia.org/wiki/Identity_element
+pub fn one<S>(vars: Vars<S, 1>)
+where
+    S: Debug + Clone + PartialEq + One,
+{
+    hint_section!("Is `one` the one of `{}`?", vars.set);
+
+    let one = Elem::new("one", S::one());
+    let is_one = Fun1::new("is_one", |x: &S| x.is_one());
+
+    ops::assert(is_one.eval(one.as_ref().eval()));
+    identity_elem(vars, Fun2::infix("*", S::mul), one);
+}
+
+/// Asserts that the functions of [`Signed`] are consistent.
+///
+/// For all `a` of `vars.set` it must hold:
+/// - `signum(a) * abs(a) == a`
+/// - `abs(-a) == abs(a)`
+/// - `is_positive(a) <-> (signum(a) == one)`
+/// - `is_negative(a) <-> (signum(a) == -one)`
+///
+/// `vars.set` must not contain NaN or elements whose negation overflows.
+pub fn signed<S>(vars: Vars<S, 1>)
+where
+    S: Debug + Clone + Signed,
+{
+    hint_section!("Are `abs` and `signum` consistent for `{}`?", vars.set);
+
+    let [a] = vars.eval();
+    let one = Elem::new("one", S::one()).eval();
+    let abs = Fun1::new("abs", |x: &S| x.abs());
+    let signum = Fun1::new("signum", |x: &S| x.signum());
+    let is_positive = Fun1::new("is_positive", |x: &S| x.is_positive());
+    let is_negative = Fun1::new("is_negative", |x: &S| x.is_negative());
+
+    let sign_times_abs = signum.eval(a.as_ref()) * abs.eval(a.as_ref());
+    ops::assert(ops::eq(sign_times_abs.as_ref(), a.as_ref()));
+
+    let neg_a = -a.clone();
+    ops::assert(ops::eq(
+        abs.eval(neg_a.as_ref()).as_ref(),
+        abs.eval(a.as_ref()).as_ref(),
+    ));
+
+    ops::assert(ops::iff(
+        is_positive.eval(a.as_ref()),
+        ops::eq(signum.eval(a.as_ref()).as_ref(), one.as_ref()),
+    ));
+
+    let neg_one = -one;
+    ops::assert(ops::iff(
+        is_negative.eval(a.as_ref()),
+        ops::eq(signum.eval(a.as_ref()).as_ref(), neg_one.as_ref()),
+    ));
+}
+
+/// Asserts that [`Bounded::min_value`] and [`Bounded::max_value`] are the [extremes] of the
+/// order of `vars.set`.
+///
+/// For all `a` of `vars.set` it must hold:
+/// - `min_value <= a`
+/// - `a <= max_value`
+///
+/// [extremes]: https://en.wikipedia.org/wiki/Maximal_and_minimal_elements
+pub fn bounded<S>(vars: Vars<S, 1>)
+where
+    S: Debug + Clone + PartialOrd + Bounded,
+{
+    hint_section!(
+        "Are `min_value` and `max_value` the extremes of `{}`?",
+        vars.set
+    );
+
+    let [a] = vars.eval();
+    let min = Elem::new("min_value", S::min_value()).eval();
+    let max = Elem::new("max_value", S::max_value()).eval();
+
+    ops::assert(ops::le(min.as_ref(), a.as_ref()));
+    ops::assert(ops::le(a.as_ref(), max.as_ref()));
+}
+
+/// Asserts that [`CheckedAdd::checked_add`] agrees with the addition of a wider type.
+///
+/// The function `widen` converts an element into the wider type.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - if `checked_add(a, b)` returns `Some(c)`, then `widen(c) == widen(a) + widen(b)`
+pub fn checked_add<S, W, F>(vars: Vars<S, 2>, widen: Fun1<F>)
+where
+    S: Debug + Clone + CheckedAdd,
+    W: Debug + PartialEq + Add<Output = W>,
+    F: Fn(S) -> W,
+{
+    let checked_add = Fun2::new("checked_add", |x: &S, y: &S| x.checked_add(y));
+    checked(vars, widen, checked_add, Fun2::infix("+", W::add));
+}
+
+/// Asserts that [`CheckedSub::checked_sub`] agrees with the subtraction of a wider type.
+///
+/// The function `widen` converts an element into the wider type.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - if `checked_sub(a, b)` returns `Some(c)`, then `widen(c) == widen(a) - widen(b)`
+pub fn checked_sub<S, W, F>(vars: Vars<S, 2>, widen: Fun1<F>)
+where
+    S: Debug + Clone + CheckedSub,
+    W: Debug + PartialEq + Sub<Output = W>,
+    F: Fn(S) -> W,
+{
+    let checked_sub = Fun2::new("checked_sub", |x: &S, y: &S| x.checked_sub(y));
+    checked(vars, widen, checked_sub, Fun2::infix("-", W::sub));
+}
+
+/// Asserts that [`CheckedMul::checked_mul`] agrees with the multiplication of a wider type.
+///
+/// The function `widen` converts an element into the wider type.
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - if `checked_mul(a, b)` returns `Some(c)`, then `widen(c) == widen(a) * widen(b)`
+pub fn checked_mul<S, W, F>(vars: Vars<S, 2>, widen: Fun1<F>)
+where
+    S: Debug + Clone + CheckedMul,
+    W: Debug + PartialEq + Mul<Output = W>,
+    F: Fn(S) -> W,
+{
+    let checked_mul = Fun2::new("checked_mul", |x: &S, y: &S| x.checked_mul(y));
+    checked(vars, widen, checked_mul, Fun2::infix("*", W::mul));
+}
+
+fn checked<S, W, F, C, O>(vars: Vars<S, 2>, widen: Fun1<F>, checked_op: Fun2<C>, op: Fun2<O>)
+where
+    S: Debug + Clone,
+    W: Debug + PartialEq,
+    F: Fn(S) -> W,
+    C: FnOnce(&S, &S) -> Option<S>,
+    O: FnOnce(W, W) -> W,
+{
+    hint_section!(
+        "Does `{}` agree with `{}` after `{}`?",
+        checked_op.name,
+        op.name,
+        widen.name,
+    );
+
+    let [a, b] = vars.eval();
+    let Eval { label, value } = checked_op.eval_once(a.as_ref(), b.as_ref());
+
+    if let Some(value) = value {
+        let c = Eval { label, value };
+        let wide = op.eval_once(widen.eval(a), widen.eval(b));
+        ops::assert(ops::eq(widen.eval(c).as_ref(), wide.as_ref()));
+    }
+}
+
+/// Asserts that the classification functions of [`Float`] are consistent.
+///
+/// For all `a` of `vars.set` it must hold:
+/// - `is_nan(a) <-> (a != a)`
+/// - `is_finite(a) <-> !(is_nan(a) || is_infinite(a))`
+/// - `is_normal(a) <-> (classify(a) == Normal)`
+/// - `is_sign_positive(a) <-> !is_sign_negative(a)`
+pub fn float_classification<S>(vars: Vars<S, 1>)
+where
+    S: Debug + Float,
+{
+    hint_section!("Is the classification of `{}` consistent?", vars.set);
+
+    let [a] = vars.eval();
+    let normal = Elem::new("Normal", FpCategory::Normal).eval();
+    let is_nan = Fun1::new("is_nan", S::is_nan);
+    let is_infinite = Fun1::new("is_infinite", S::is_infinite);
+    let is_finite = Fun1::new("is_finite", S::is_finite);
+    let is_normal = Fun1::new("is_normal", S::is_normal);
+    let classify = Fun1::new("classify", S::classify);
+    let is_sign_positive = Fun1::new("is_sign_positive", S::is_sign_positive);
+    let is_sign_negative = Fun1::new("is_sign_negative", S::is_sign_negative);
+
+    ops::assert(ops::iff(is_nan.eval(a), ops::ne(a.as_ref(), a.as_ref())));
+    ops::assert(ops::iff(
+        is_finite.eval(a),
+        ops::not(ops::or(is_nan.eval(a), is_infinite.eval(a))),
+    ));
+    ops::assert(ops::iff(
+        is_normal.eval(a),
+        ops::eq(classify.eval(a).as_ref(), normal.as_ref()),
+    ));
+    ops::assert(ops::iff(
+        is_sign_positive.eval(a),
+        ops::not(is_sign_negative.eval(a)),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+
+    use crate::{props, Fun1, Set};
+
+    #[test]
+    fn zero_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i32", dice::i32(..));
+            let vars = fate.roll(set.vars(["x"]));
+            props::num::zero(vars);
+        })
+    }
+
+    #[test]
+    fn one_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("f64", dice::f64(-1000.0..=1000.0));
+            let vars = fate.roll(set.vars(["x"]));
+            props::num::one(vars);
+        })
+    }
+
+    #[test]
+    fn signed_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i32 \\ {min_value}", dice::i32(i32::MIN + 1..));
+            let vars = fate.roll(set.vars(["x"]));
+            props::num::signed(vars);
+        })
+    }
+
+    #[test]
+    fn bounded_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("u16", dice::u16(..));
+            let vars = fate.roll(set.vars(["x"]));
+            props::num::bounded(vars);
+        })
+    }
+
+    #[test]
+    fn checked_add_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i8", dice::i8(..));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let widen = Fun1::new("i16::from", i16::from);
+            props::num::checked_add(vars, widen);
+        })
+    }
+
+    #[test]
+    fn checked_sub_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i8", dice::i8(..));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let widen = Fun1::new("i16::from", i16::from);
+            props::num::checked_sub(vars, widen);
+        })
+    }
+
+    #[test]
+    fn checked_mul_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("i8", dice::i8(..));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            let widen = Fun1::new("i16::from", i16::from);
+            props::num::checked_mul(vars, widen);
+        })
+    }
+
+    #[test]
+    fn float_classification_example() {
+        Dicetest::repeatedly().run(|mut fate| {
+            let set = Set::new("f32", dice::any_f32());
+            let vars = fate.roll(set.vars(["x"]));
+            props::num::float_classification(vars);
+        })
+    }
+}
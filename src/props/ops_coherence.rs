@@ -0,0 +1,321 @@
+//! Properties for the coherence of the operator traits of [`std::ops`] and [`std::iter`].
+//!
+//! The operator traits are implemented independently of each other, hence nothing prevents e.g.
+//! [`AddAssign`] from behaving differently than [`Add`]. The properties in this module assert
+//! that related implementations agree.
+
+use dicetest::hint_section;
+use std::fmt::Debug;
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::props::{binop, fun};
+use crate::{ops, Elem, Fun1, Fun2, Vars};
+
+macro_rules! assign_op_prop {
+    ($(#[$attr:meta])* $fn:ident, $trait:ident, $method:ident, $name:literal, $assign_trait:ident, $assign_method:ident, $assign_name:literal) => {
+        $(#[$attr])*
+        pub fn $fn<S>(vars: Vars<S, 2>)
+        where
+            S: Debug + Clone + PartialEq + $trait<Output = S> + $assign_trait,
+        {
+            let op = Fun2::infix($name, S::$method);
+            let assign_op = Fun2::infix($assign_name, |mut a: S, b: S| {
+                a.$assign_method(b);
+                a
+            });
+
+            binop::equal(vars, op, assign_op);
+        }
+    };
+}
+
+macro_rules! non_zero_assign_op_prop {
+    ($(#[$attr:meta])* $fn:ident, $trait:ident, $method:ident, $name:literal, $assign_trait:ident, $assign_method:ident, $assign_name:literal) => {
+        $(#[$attr])*
+        pub fn $fn<S>(vars: Vars<S, 1>, non_zero_vars: Vars<S, 1>)
+        where
+            S: Debug + Clone + PartialEq + $trait<Output = S> + $assign_trait,
+        {
+            let op = Fun2::infix($name, S::$method);
+            let assign_op = Fun2::infix($assign_name, |mut a: S, b: S| {
+                a.$assign_method(b);
+                a
+            });
+
+            fun::equal_2(vars, non_zero_vars, op, assign_op);
+        }
+    };
+}
+
+assign_op_prop!(
+    /// Asserts that [`AddAssign`] is coherent with [`Add`].
+    ///
+    /// For all `a`, `b` of `vars.set` it must hold:
+    /// - `{ a += b; a } == a + b`
+    add_assign, Add, add, "+", AddAssign, add_assign, "+="
+);
+
+assign_op_prop!(
+    /// Asserts that [`SubAssign`] is coherent with [`Sub`].
+    ///
+    /// For all `a`, `b` of `vars.set` it must hold:
+    /// - `{ a -= b; a } == a - b`
+    sub_assign, Sub, sub, "-", SubAssign, sub_assign, "-="
+);
+
+assign_op_prop!(
+    /// Asserts that [`MulAssign`] is coherent with [`Mul`].
+    ///
+    /// For all `a`, `b` of `vars.set` it must hold:
+    /// - `{ a *= b; a } == a * b`
+    mul_assign, Mul, mul, "*", MulAssign, mul_assign, "*="
+);
+
+non_zero_assign_op_prop!(
+    /// Asserts that [`DivAssign`] is coherent with [`Div`].
+    ///
+    /// For all `a` of `vars.set` and `b` of `non_zero_vars.set` it must hold:
+    /// - `{ a /= b; a } == a / b`
+    div_assign, Div, div, "/", DivAssign, div_assign, "/="
+);
+
+non_zero_assign_op_prop!(
+    /// Asserts that [`RemAssign`] is coherent with [`Rem`].
+    ///
+    /// For all `a` of `vars.set` and `b` of `non_zero_vars.set` it must hold:
+    /// - `{ a %= b; a } == a % b`
+    rem_assign, Rem, rem, "%", RemAssign, rem_assign, "%="
+);
+
+assign_op_prop!(
+    /// Asserts that [`BitAndAssign`] is coherent with [`BitAnd`].
+    ///
+    /// For all `a`, `b` of `vars.set` it must hold:
+    /// - `{ a &= b; a } == a & b`
+    bitand_assign, BitAnd, bitand, "&", BitAndAssign, bitand_assign, "&="
+);
+
+assign_op_prop!(
+    /// Asserts that [`BitOrAssign`] is coherent with [`BitOr`].
+    ///
+    /// For all `a`, `b` of `vars.set` it must hold:
+    /// - `{ a |= b; a } == a | b`
+    bitor_assign, BitOr, bitor, "|", BitOrAssign, bitor_assign, "|="
+);
+
+assign_op_prop!(
+    /// Asserts that [`BitXorAssign`] is coherent with [`BitXor`].
+    ///
+    /// For all `a`, `b` of `vars.set` it must hold:
+    /// - `{ a ^= b; a } == a ^ b`
+    bitxor_assign, BitXor, bitxor, "^", BitXorAssign, bitxor_assign, "^="
+);
+
+/// Asserts that [`Sub`] is coherent with [`Add`] and [`Neg`].
+///
+/// For all `a`, `b` of `vars.set` it must hold:
+/// - `a - b == a + (-b)`
+pub fn sub_neg<S>(vars: Vars<S, 2>)
+where
+    S: Debug + Clone + PartialEq + Add<Output = S> + Sub<Output = S> + Neg<Output = S>,
+{
+    hint_section!("Is `-` equal to `+` of the negated right operand?");
+
+    let [a, b] = vars.eval();
+
+    let lhs = a.clone() - b.clone();
+    let rhs = a + (-b);
+
+    ops::assert(ops::eq(lhs.as_ref(), rhs.as_ref()));
+}
+
+/// Asserts that [`Div`] and [`Rem`] are coherent with [`Mul`] and [`Add`].
+///
+/// For all `a` of `vars.set` and `b` of `non_zero_vars.set` it must hold:
+/// - `(a / b) * b + a % b == a`
+pub fn div_rem<S>(vars: Vars<S, 1>, non_zero_vars: Vars<S, 1>)
+where
+    S: Debug
+        + Clone
+        + PartialEq
+        + Add<Output = S>
+        + Mul<Output = S>
+        + Div<Output = S>
+        + Rem<Output = S>,
+{
+    hint_section!("Are `/` and `%` consistent?");
+
+    let [a] = vars.eval();
+    let [b] = non_zero_vars.eval();
+
+    let lhs = (a.clone() / b.clone()) * b.clone() + a.clone() % b;
+
+    ops::assert(ops::eq(lhs.as_ref(), a.as_ref()));
+}
+
+/// Asserts that [`Sum`] is coherent with [`Add`].
+///
+/// For all `xs` of `vars.set` it must hold:
+/// - `sum(xs) == fold_add(xs, zero)`
+pub fn sum<S>(vars: Vars<Vec<S>, 1>, zero: Elem<S>)
+where
+    S: Debug + Clone + PartialEq + Add<Output = S> + Sum,
+{
+    hint_section!("Is `sum` equal to the fold with `+` and `{}`?", zero.name);
+
+    let [xs] = vars.eval();
+    let zero = zero.eval();
+    let sum = Fun1::new("sum", |xs: Vec<S>| xs.into_iter().sum::<S>());
+    let fold_add = Fun2::new("fold_add", |xs: Vec<S>, zero| {
+        xs.into_iter().fold(zero, S::add)
+    });
+
+    ops::assert(ops::eq(
+        sum.eval_once(xs.clone()).as_ref(),
+        fold_add.eval_once(xs, zero).as_ref(),
+    ));
+}
+
+/// Asserts that [`Product`] is coherent with [`Mul`].
+///
+/// For all `xs` of `vars.set` it must hold:
+/// - `product(xs) == fold_mul(xs, one)`
+pub fn product<S>(vars: Vars<Vec<S>, 1>, one: Elem<S>)
+where
+    S: Debug + Clone + PartialEq + Mul<Output = S> + Product,
+{
+    hint_section!(
+        "Is `product` equal to the fold with `*` and `{}`?",
+        one.name
+    );
+
+    let [xs] = vars.eval();
+    let one = one.eval();
+    let product = Fun1::new("product", |xs: Vec<S>| xs.into_iter().product::<S>());
+    let fold_mul = Fun2::new("fold_mul", |xs: Vec<S>, one| {
+        xs.into_iter().fold(one, S::mul)
+    });
+
+    ops::assert(ops::eq(
+        product.eval_once(xs.clone()).as_ref(),
+        fold_mul.eval_once(xs, one).as_ref(),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use dicetest::prelude::*;
+    use std::ops::{Add, AddAssign};
+
+    use crate::{check, props, Elem, Set, Vars};
+
+    macro_rules! assign_op_example {
+        ($test:ident, $prop:ident) => {
+            #[test]
+            fn $test() {
+                Dicetest::once().run(|mut fate| {
+                    let set = Set::new("i64", dice::i64(-1000..=1000));
+                    let vars = fate.roll(set.vars(["x", "y"]));
+                    props::ops_coherence::$prop(vars);
+                })
+            }
+        };
+    }
+
+    macro_rules! non_zero_assign_op_example {
+        ($test:ident, $prop:ident) => {
+            #[test]
+            fn $test() {
+                Dicetest::once().run(|mut fate| {
+                    let set = Set::new("i64", dice::i64(-1000..=1000));
+                    let non_zero_set = Set::new("i64 ∩ [1,1000]", dice::i64(1..=1000));
+                    let vars = fate.roll(set.vars(["x"]));
+                    let non_zero_vars = fate.roll(non_zero_set.vars(["y"]));
+                    props::ops_coherence::$prop(vars, non_zero_vars);
+                })
+            }
+        };
+    }
+
+    assign_op_example!(add_assign_example, add_assign);
+    assign_op_example!(sub_assign_example, sub_assign);
+    assign_op_example!(mul_assign_example, mul_assign);
+    non_zero_assign_op_example!(div_assign_example, div_assign);
+    non_zero_assign_op_example!(rem_assign_example, rem_assign);
+    assign_op_example!(bitand_assign_example, bitand_assign);
+    assign_op_example!(bitor_assign_example, bitor_assign);
+    assign_op_example!(bitxor_assign_example, bitxor_assign);
+
+    #[test]
+    fn add_assign_detects_incoherent_impl() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Counter(i64);
+
+        impl Add for Counter {
+            type Output = Counter;
+
+            fn add(self, other: Counter) -> Counter {
+                Counter(self.0 + other.0)
+            }
+        }
+
+        impl AddAssign for Counter {
+            fn add_assign(&mut self, other: Counter) {
+                self.0 = other.0;
+            }
+        }
+
+        let vars = Vars::new(
+            "Counter",
+            [Elem::new("x", Counter(1)), Elem::new("y", Counter(2))],
+        );
+
+        let failure = check(|| props::ops_coherence::add_assign(vars)).unwrap_err();
+
+        assert_eq!(failure.section.unwrap(), "Is `+=` equal to `+`?");
+    }
+
+    #[test]
+    fn sub_neg_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let vars = fate.roll(set.vars(["x", "y"]));
+            props::ops_coherence::sub_neg(vars);
+        })
+    }
+
+    #[test]
+    fn div_rem_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("i64", dice::i64(-1000..=1000));
+            let non_zero_set = Set::new("i64 ∩ [1,1000]", dice::i64(1..=1000));
+            let vars = fate.roll(set.vars(["x"]));
+            let non_zero_vars = fate.roll(non_zero_set.vars(["y"]));
+            props::ops_coherence::div_rem(vars, non_zero_vars);
+        })
+    }
+
+    #[test]
+    fn sum_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<i64>", dice::vec(dice::i64(-1000..=1000), ..));
+            let vars = fate.roll(set.vars(["xs"]));
+            let zero = Elem::new("zero", 0);
+            props::ops_coherence::sum(vars, zero);
+        })
+    }
+
+    #[test]
+    fn product_example() {
+        Dicetest::once().run(|mut fate| {
+            let set = Set::new("Vec<f64>", dice::vec(dice::f64(-2.0..=2.0), ..));
+            let vars = fate.roll(set.vars(["xs"]));
+            let one = Elem::new("one", 1.0);
+            props::ops_coherence::product(vars, one);
+        })
+    }
+}